@@ -1,17 +1,25 @@
+use std::collections::HashSet;
+
 use serde::{Serialize, Deserialize};
-#[allow(unused)] // Compiler believes that Game's methods and attributes are unused
-                 // TODO: HIDING UNUSED, DEAD, OLD ETC. WARNINGS
+
+use crate::map::{Heightmap, Map, SpawnRegion, TerrainFeature};
+use crate::navigation;
+use crate::rng::Rng;
 
 // Error definitions BEGIN
 #[derive(Debug, Serialize)]
+#[allow(clippy::enum_variant_names)] // Variant names are part of the serialized API
 pub enum ArtilleryError {
     IndexError(String),
     DistanceError(String),
     ResourceError(String),
+    ConfigError(String),
+    TerrainError(String),
+    MapError(String),
+    OrderError(String),
 }
 // TODO: Update error messages to use proper serialization, not the impl of debug
 // TODO: DRY errors?
-// TODO: Shouldn't be able to place units within the base with `add_unit`
 // TODO: UNIT RESOURCE COST, SIMILAR TO ARTILLERY COSTS?
 
 impl ArtilleryError {
@@ -34,12 +42,32 @@ impl ArtilleryError {
         let error_msg = format!("{func_name} failed to {action}.");
         ArtilleryError::ResourceError(error_msg)
     }
+
+    pub fn config_error(func_name: &str, reason: &str) -> ArtilleryError {
+        let error_msg = format!("{func_name} failed to apply the config. {reason}.");
+        ArtilleryError::ConfigError(error_msg)
+    }
+
+    pub fn terrain_error(func_name: &str, action: &str, coord: &Coordinate) -> ArtilleryError {
+        let error_msg = format!("{func_name} failed to {action}. {coord:?} is blocked by impassable terrain.");
+        ArtilleryError::TerrainError(error_msg)
+    }
+
+    pub fn map_error(func_name: &str, reason: &str) -> ArtilleryError {
+        let error_msg = format!("{func_name} failed to load the map. {reason}.");
+        ArtilleryError::MapError(error_msg)
+    }
+
+    pub fn order_error(func_name: &str, reason: &str) -> ArtilleryError {
+        let error_msg = format!("{func_name} failed to accept the order. {reason}.");
+        ArtilleryError::OrderError(error_msg)
+    }
     
 }
 // Error definitions END
 //
 // Coordinate definitions BEGIN
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Coordinate {
     pub x: f32,
     pub y: f32
@@ -62,73 +90,841 @@ impl Coordinate {
     pub fn contains(&self, coord2:&Coordinate, radius:f32) -> bool {
         radius >= self.distance(coord2)
     }
+
+    /// `distance_to_segment` accepts the `start` and `end` of a straight line, and returns the
+    /// shortest distance between this Coordinate and any point on that line.
+    pub fn distance_to_segment(&self, start:&Coordinate, end:&Coordinate) -> f32 {
+        self.distance(&self.closest_point_on_segment(start, end))
+    }
+
+    /// `closest_point_on_segment` accepts the `start` and `end` of a straight line, and returns the
+    /// point on that line closest to this Coordinate.
+    pub fn closest_point_on_segment(&self, start:&Coordinate, end:&Coordinate) -> Coordinate {
+        let length_squared = (end.x - start.x).powf(2.0) + (end.y - start.y).powf(2.0);
+        if length_squared == 0.0 {
+            return start.clone();
+        }
+        // Project onto the line, then clamp the projection to the segment
+        let t = (((self.x - start.x) * (end.x - start.x) + (self.y - start.y) * (end.y - start.y)) / length_squared).clamp(0.0, 1.0);
+        start.along(end, t)
+    }
+
+    /// `along` accepts another Coordinate, `end`, and returns the point `fraction` (0.0 - 1.0) of
+    /// the way from this Coordinate to `end`.
+    pub fn along(&self, end:&Coordinate, fraction:f32) -> Coordinate {
+        Coordinate { x: self.x + (end.x - self.x) * fraction, y: self.y + (end.y - self.y) * fraction }
+    }
 }
 // Coordinate definitions END
 //
+// Unit definitions BEGIN
+/// `UnitKind` is the type of a unit in the army.
+///
+/// Decoys look identical to soldiers from the artillery player's side, but they can't capture
+/// the base, and the army loses once only decoys remain.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnitKind {
+    #[default]
+    Soldier,
+    Decoy,
+}
+
+/// `UnitOrder` is the payload used to create a unit. `kind` defaults to `Soldier`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UnitOrder {
+    pub x: f32,
+    pub y: f32,
+    #[serde(default)]
+    pub kind: UnitKind,
+}
+
+/// `Stance` is a unit's order for the current turn, on top of its destination (see
+/// `Game::set_stance`). Units without a stance move at their usual pace.
+///
+/// - `Hold` -> The unit stays where it is this turn, and carries on along its route next turn.
+/// - `DigIn` -> The unit stays where it is and abandons its route, but blasts have a smaller
+///   radius against it (see `StanceEffects`).
+/// - `Sprint` -> The unit can move farther this turn, but takes extra damage next turn.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stance {
+    Hold,
+    DigIn,
+    Sprint,
+}
+
+/// `StanceOrder` is the payload used to set a unit's stance. A missing or `null` `stance` clears
+/// it.
+#[derive(Debug, Clone, Deserialize)]
+pub struct StanceOrder {
+    #[serde(default)]
+    pub stance: Option<Stance>,
+}
+
+/// `Formation` is the shape a group of units takes around its destination (see
+/// `Game::set_group_destination`). Formations face the way the group is travelling.
+///
+/// - `Line` -> Side by side, across the direction of travel.
+/// - `Wedge` -> An arrowhead, with the first unit at its tip.
+/// - `Column` -> One behind another, with the first unit in front.
+/// - `Spread` -> A square grid.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Formation {
+    #[default]
+    Line,
+    Wedge,
+    Column,
+    Spread,
+}
+
+impl Formation {
+    /// The distance between neighbouring units when a group order doesn't set one.
+    pub const DEFAULT_SPACING: f32 = 2.0;
+
+    /// `offsets` accepts the number of units in a group, and the `spacing` between them, and
+    /// returns where each unit stands relative to the group's destination. `x` runs to the right of
+    /// the direction of travel, and `y` runs forward.
+    pub fn offsets(&self, count:usize, spacing:f32) -> Vec<Coordinate> {
+        let centered = |index:usize, total:usize| (index as f32 - (total as f32 - 1.0) / 2.0) * spacing;
+        match self {
+            Formation::Line => (0..count).map(|index| Coordinate { x: centered(index, count), y: 0.0 }).collect(),
+            Formation::Column => (0..count).map(|index| Coordinate { x: 0.0, y: -(index as f32) * spacing }).collect(),
+            Formation::Wedge => (0..count)
+                .map(|index| {
+                    // Units fall back in pairs, alternating left and right of the tip
+                    let rank = index.div_ceil(2) as f32;
+                    let side = if index % 2 == 1 { -1.0 } else { 1.0 };
+                    Coordinate { x: side * rank * spacing, y: -rank * spacing }
+                })
+                .collect(),
+            Formation::Spread => {
+                let columns = (count as f32).sqrt().ceil().max(1.0) as usize;
+                let rows = count.div_ceil(columns);
+                (0..count)
+                    .map(|index| Coordinate { x: centered(index % columns, columns), y: -centered(index / columns, rows) })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// `GroupOrder` is the payload used to move several units at once. `formation` defaults to
+/// `Line`, and `spacing` defaults to `Formation::DEFAULT_SPACING`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GroupOrder {
+    pub units: Vec<usize>,
+    pub x: f32,
+    pub y: f32,
+    #[serde(default)]
+    pub formation: Formation,
+    pub spacing: Option<f32>,
+}
+
+/// `GroupMove` is one unit's part in a group order (see `Game::set_group_destination`).
+///
+/// `slot` is where the unit was sent, and `destination` is the point it will reach this turn.
+/// Units that couldn't comply have no `destination`, and an `error` explaining why.
+#[derive(Debug, Serialize)]
+pub struct GroupMove {
+    pub unit: usize,
+    pub slot: Coordinate,
+    pub destination: Option<Coordinate>,
+    pub error: Option<ArtilleryError>,
+}
+// Unit definitions END
+//
+// Munition definitions BEGIN
+/// `Munition` is the type of shell fired at a target. Each munition has its own cost multiplier
+/// (see `cost_multiplier`) and effect (see `Game::detonations`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Munition {
+    /// A single blast of `target_radius`
+    #[default]
+    Standard,
+    /// A wide blast that deals reduced damage
+    Airburst,
+    /// A cloud that hides units from the artillery player's view for the next turn
+    Smoke,
+    /// Several smaller blasts spread around the target
+    Cluster,
+    /// A single blast that detonates `FUSE_DELAY` ticks after it lands
+    DelayedFuse,
+}
+
+impl Munition {
+    /// Number of sub-blasts a `Cluster` shell spreads around its target, on top of the one on the
+    /// target itself.
+    pub const CLUSTER_BOMBLETS: usize = 5;
+    /// Number of ticks a `DelayedFuse` shell waits before detonating.
+    pub const FUSE_DELAY: usize = 25;
+
+    /// `cost_multiplier` returns the factor applied to the base shot cost for this munition.
+    pub fn cost_multiplier(&self) -> f32 {
+        match self {
+            Munition::Standard => 1.0,
+            Munition::Airburst => 1.25,
+            Munition::Smoke => 0.75,
+            Munition::Cluster => 1.5,
+            Munition::DelayedFuse => 1.1,
+        }
+    }
+
+    /// `barrage_damage` returns the damage dealt by each blast of a line or creeping barrage
+    /// fired with this munition (see `TargetShape`).
+    pub fn barrage_damage(&self) -> f32 {
+        match self {
+            Munition::Standard | Munition::Cluster | Munition::DelayedFuse => 1.0,
+            Munition::Airburst => 0.5,
+            Munition::Smoke => 0.0,
+        }
+    }
+}
+
+/// `TargetShape` is the area a target covers. The target's own position is the start of the
+/// shape.
+///
+/// Barrages are priced by the ground they cover: the shot cost is calculated over the distance to
+/// the start of the barrage plus its length, scaled by its width, and the battery's next shot is
+/// measured from its `end` (see `Game::shot_cost`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TargetShape {
+    /// A single shell, whose blasts are set by its `Munition`
+    #[default]
+    Point,
+    /// A barrage that covers the line to `end`, `width` wide, all at once
+    Line { end: Coordinate, width: f32 },
+    /// A barrage that sweeps along the line to `end`, `width` wide, over `duration` ticks
+    Creeping { end: Coordinate, width: f32, duration: usize },
+}
+
+impl TargetShape {
+    /// The widest a barrage can be, in multiples of `target_radius`: as wide as a single shell's
+    /// blast.
+    pub const MAX_WIDTH: f32 = 2.0;
+
+    /// `end` accepts the `start` of the shape (the target's position), and returns where the
+    /// shape ends. Points end where they start.
+    pub fn end(&self, start: &Coordinate) -> Coordinate {
+        match self {
+            TargetShape::Point => start.clone(),
+            TargetShape::Line { end, .. } | TargetShape::Creeping { end, .. } => end.clone(),
+        }
+    }
+
+    /// `length` accepts the `start` of the shape, and returns the length of the line it covers.
+    pub fn length(&self, start: &Coordinate) -> f32 {
+        start.distance(&self.end(start))
+    }
+
+    /// `width` returns how wide the shape is. Points have no width of their own.
+    pub fn width(&self) -> f32 {
+        match self {
+            TargetShape::Point => 0.0,
+            TargetShape::Line { width, .. } | TargetShape::Creeping { width, .. } => *width,
+        }
+    }
+}
+
+/// `Detonation` is a single blast (or smoke cloud) produced by a target during `Game::run_turn`.
+///
+/// Line barrages produce a single blast covering the line from `center` to `end`. Every other
+/// blast is a circle around `center`, and has no `end`.
+#[derive(Debug, Clone)]
+struct Detonation {
+    center: Coordinate,
+    end: Option<Coordinate>,
+    radius: f32,
+    damage: f32,
+    tick: usize,
+    smoke: bool,
+}
+
+/// `TargetOrder` is the payload used to create a target. `munition` defaults to `Standard`,
+/// `shape` defaults to `Point`, and `battery` defaults to 0, the battery at the base.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TargetOrder {
+    pub x: f32,
+    pub y: f32,
+    #[serde(default)]
+    pub munition: Munition,
+    #[serde(default)]
+    pub shape: TargetShape,
+    #[serde(default)]
+    pub battery: usize,
+}
+// Munition definitions END
+//
+// Visibility definitions BEGIN
+/// `Sighting` is the artillery player's last view of a unit.
+///
+/// `age` is the number of turns since the unit was last spotted at `position` (see
+/// `Game::get_last_known_units`).
+#[derive(Debug, Clone, Serialize)]
+pub struct Sighting {
+    pub position: Coordinate,
+    pub age: usize,
+}
+// Visibility definitions END
+//
+// Objective definitions BEGIN
+/// `Objective` is a point on the map the army can capture. It is held on any tick where at least
+/// one soldier is within `radius` of `center`.
+///
+/// In scoring mode, holding the objective for a whole turn earns the army `value` points.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Objective {
+    pub center: Coordinate,
+    pub radius: f32,
+    #[serde(default = "Objective::default_value")]
+    pub value: f32,
+}
+
+impl Objective {
+    /// `default_value` returns the points earned for holding an objective for a turn when it
+    /// doesn't set its own `value`.
+    fn default_value() -> f32 {
+        1.0
+    }
+}
+
+/// `Objectives` are the capture points on the map, and the rules for winning with them. Each
+/// rule is optional, and the army wins as soon as either of them is met (or a soldier reaches the
+/// base):
+///
+/// - `hold_ticks` -> A single objective is held for this many ticks in a row.
+/// - `hold_turns` -> A single objective is held at the end of this many turns in a row.
+///
+/// Holding objectives also earns the army points (see `Objective` and `VictoryCondition`).
+///
+/// `score_to_win` is deprecated, and is read as `VictoryCondition.army_score` so that older
+/// configs keep working. It is never reported back.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Objectives {
+    pub points: Vec<Objective>,
+    pub hold_ticks: Option<usize>,
+    pub hold_turns: Option<usize>,
+    #[serde(skip_serializing)]
+    pub score_to_win: Option<f32>,
+}
+// Objective definitions END
+//
+// Victory definitions BEGIN
+/// `VictoryCondition` holds the optional ways to end a game besides the army being wiped out, or
+/// a soldier reaching the base:
+///
+/// - `max_turns` -> The artillery player wins if the army hasn't won after this many turns.
+/// - `attrition` -> The artillery player wins once this fraction (0.0 - 1.0) of the army's
+///   soldiers have been destroyed. Decoys don't count.
+/// - `army_score` -> The army wins once its score from holding objectives reaches this many
+///   points.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VictoryCondition {
+    pub max_turns: Option<usize>,
+    pub attrition: Option<f32>,
+    pub army_score: Option<f32>,
+}
+
+/// `WinReason` is the reason a game ended, reported with the winner of the final turn.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WinReason {
+    /// A soldier reached the base, or a besieged base ran out of hit points (see `Siege`)
+    BaseCaptured,
+    /// An objective was held for long enough (see `Objectives`)
+    ObjectiveHeld,
+    /// The army's score reached `VictoryCondition.army_score`
+    ArmyScore,
+    /// No soldiers are left on the map
+    ArmyDestroyed,
+    /// `VictoryCondition.attrition` of the army's soldiers were destroyed
+    Attrition,
+    /// The game reached `VictoryCondition.max_turns`
+    TurnLimit,
+}
+
+impl WinReason {
+    /// `winner` returns the player who wins for this reason: 1 for the army, 2 for the artillery.
+    pub fn winner(&self) -> usize {
+        match self {
+            WinReason::BaseCaptured | WinReason::ObjectiveHeld | WinReason::ArmyScore => 1,
+            WinReason::ArmyDestroyed | WinReason::Attrition | WinReason::TurnLimit => 2,
+        }
+    }
+}
+// Victory definitions END
+//
+// Config definitions BEGIN
+/// `ReinforcementSchedule` describes the waves of units the army receives between turns.
+///
+/// Every `turn_interval` turns, `units_per_wave` units are placed in the spawn region (see
+/// `SpawnRegion`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReinforcementSchedule {
+    pub units_per_wave: usize,
+    pub turn_interval: usize,
+}
+
+/// `Economy` controls how the artillery player's resources carry over between turns.
+///
+/// At the end of each turn, `bank_rate` (0.0 - 1.0) of the unspent resources are banked, along
+/// with `kill_bonus` for every unit destroyed that turn. The bank never exceeds `bank_cap`, and is
+/// added on top of `Game.max_resources` for the following turn.
+///
+/// Nothing is banked by default. `bank_cap` defaults to a full turn's resources, so that setting
+/// `bank_rate` or `kill_bonus` alone is enough to start banking.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Economy {
+    pub bank_rate: f32,
+    pub bank_cap: f32,
+    pub kill_bonus: f32,
+}
+
+impl Default for Economy {
+    fn default() -> Self {
+        Economy { bank_rate: 0.0, bank_cap: 100.0, kill_bonus: 0.0 }
+    }
+}
+
+/// `Recon` controls the artillery player's spotters (see `Game::add_spotter`).
+///
+/// Each spotter costs `spotter_cost` resources, and reveals units within `spotter_radius` of it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Recon {
+    pub spotter_cost: f32,
+    pub spotter_radius: f32,
+}
+
+impl Recon {
+    /// Units that come this close to a spotter destroy it.
+    pub const SPOTTER_FOOTPRINT: f32 = 1.0;
+}
+
+impl Default for Recon {
+    fn default() -> Self {
+        Recon { spotter_cost: 20.0, spotter_radius: 20.0 }
+    }
+}
+
+/// `CounterBattery` controls how the army fights back against the artillery (see
+/// `Game::set_raid`).
+///
+/// A soldier ordered to raid disables every battery and spotter within `range` of where it ends
+/// the turn.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CounterBattery {
+    pub range: f32,
+}
+
+impl Default for CounterBattery {
+    fn default() -> Self {
+        CounterBattery { range: 5.0 }
+    }
+}
+
+/// `StanceEffects` controls what each `Stance` does.
+///
+/// Blasts against a dug-in unit have `dig_in_radius` times their usual radius. A sprinting unit
+/// can move `sprint_range` times `Game.max_unit_range` this turn, and takes `sprint_vulnerability`
+/// times the usual damage during the next turn.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StanceEffects {
+    pub dig_in_radius: f32,
+    pub sprint_range: f32,
+    pub sprint_vulnerability: f32,
+}
+
+impl Default for StanceEffects {
+    fn default() -> Self {
+        StanceEffects { dig_in_radius: 0.5, sprint_range: 2.0, sprint_vulnerability: 1.5 }
+    }
+}
+
+/// `Siege` gives the base hit points, so that a single soldier can't capture it alone.
+///
+/// A soldier that reaches `Game.base_radius` deals `damage_per_unit` to the base once, on the
+/// tick it arrives, and is consumed; it doesn't keep draining the base. The base is checked every
+/// tick, so soldiers arriving over the course of a turn drain it bit by bit. The army captures the
+/// base once its `base_health` runs out, which takes `base_health / damage_per_unit` soldiers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Siege {
+    pub base_health: f32,
+    pub damage_per_unit: f32,
+}
+
+impl Default for Siege {
+    fn default() -> Self {
+        Siege { base_health: 100.0, damage_per_unit: 10.0 }
+    }
+}
+
+/// `Suppression` lets shells pin down the units they miss.
+///
+/// Every unit within `radius_multiplier` times a blast's radius is suppressed: its velocity is
+/// multiplied by `speed_multiplier` for the rest of the turn. A unit can only be suppressed once
+/// per turn.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Suppression {
+    pub radius_multiplier: f32,
+    pub speed_multiplier: f32,
+}
+
+impl Default for Suppression {
+    fn default() -> Self {
+        Suppression { radius_multiplier: 2.0, speed_multiplier: 0.5 }
+    }
+}
+
+/// `Turret` is a point-defense turret that protects the base without the artillery player's input.
+///
+/// Every `fire_interval` ticks (starting on tick 0), the turret fires at the nearest unit within
+/// `range` of its `position`, dealing `damage`. Turrets can't fire through impassable terrain, and
+/// can't see units that are camouflaged or inside smoke.
+///
+/// A turret with no `position` stands at the base, and moves with it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Turret {
+    pub position: Option<Coordinate>,
+    pub range: f32,
+    pub fire_interval: usize,
+    pub damage: f32,
+}
+
+impl Turret {
+    /// `position_or` accepts the `base_coords`, and returns where the turret stands: its own
+    /// `position`, or the base if it has none.
+    pub fn position_or<'a>(&'a self, base_coords: &'a Coordinate) -> &'a Coordinate {
+        self.position.as_ref().unwrap_or(base_coords)
+    }
+}
+
+impl Default for Turret {
+    fn default() -> Self {
+        Turret { position: None, range: 10.0, fire_interval: 10, damage: 0.25 }
+    }
+}
+
+/// `GameConfig` is a partial set of settings accepted by `Game::configure`.
+///
+/// Every field is optional; missing fields leave the current setting untouched. Settings that can
+/// be turned off, like `spotting_radius`, are cleared with `null` (see `deserialize_clearable`).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct GameConfig {
+    pub reinforcements: Option<ReinforcementSchedule>,
+    pub economy: Option<Economy>,
+    pub seed: Option<u64>,
+    pub dispersion: Option<f32>,
+    pub max_wind: Option<f32>,
+    pub flight_time: Option<f32>,
+    #[serde(deserialize_with = "deserialize_clearable")]
+    pub spotting_radius: Option<Option<f32>>,
+    pub recon: Option<Recon>,
+    pub counter_battery: Option<CounterBattery>,
+    pub stance_effects: Option<StanceEffects>,
+    pub objectives: Option<Objectives>,
+    pub victory: Option<VictoryCondition>,
+    pub siege: Option<Siege>,
+    pub suppression: Option<Suppression>,
+    pub batteries: Option<Vec<Coordinate>>,
+    pub defenses: Option<Vec<Turret>>,
+    pub terrain: Option<Vec<TerrainFeature>>,
+    pub elevation: Option<Heightmap>,
+    pub base_coords: Option<Coordinate>,
+    pub spawn_region: Option<SpawnRegion>,
+    pub unit_footprint: Option<f32>,
+}
+
+impl GameConfig {
+    /// The largest `dispersion`: a shell can stray as far as the distance it was fired.
+    pub const MAX_DISPERSION: f32 = 1.0;
+    /// The strongest `max_wind`, in units a shell is pushed: enough to blow a shell across a
+    /// default map.
+    pub const MAX_WIND: f32 = 200.0;
+    /// The longest `flight_time`, in ticks per unit of distance. A shell fired this slowly at a
+    /// target 10 units away spends a whole turn in the air.
+    pub const MAX_FLIGHT_TIME: f32 = 10.0;
+}
+
+/// `deserialize_clearable` reads a setting that can be turned off. A missing setting is None, and
+/// is left untouched; `null` is Some(None), and turns the setting off; any other value is
+/// Some(Some(value)).
+fn deserialize_clearable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+// Config definitions END
+//
+// Report definitions BEGIN
+/// `ShotTiming` describes when a target's shell is fired, and when it takes effect.
+///
+/// `fire_tick` is the shell's place in the cost chain. `impact_tick` adds the shell's flight time
+/// and, for `DelayedFuse` shells, the fuse delay.
+#[derive(Debug, Clone, Serialize)]
+pub struct ShotTiming {
+    pub fire_tick: usize,
+    pub impact_tick: usize,
+}
+
+/// `TurretShot` is a single shot fired by a `Turret` during `Game::run_turn`.
+///
+/// `turret` is the turret's index in `Game.defenses`, and `target` is where the unit it hit stood.
+#[derive(Debug, Clone, Serialize)]
+pub struct TurretShot {
+    pub turret: usize,
+    pub tick: usize,
+    pub target: Coordinate,
+}
+
+/// `TurnReport` summarizes the outcome of the most recent call to `Game::run_turn`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TurnReport {
+    pub turn: usize,
+    pub winner: usize,
+    pub reason: Option<WinReason>,
+    pub reinforcements: Vec<Coordinate>,
+    pub units_destroyed: usize,
+    pub spotters_destroyed: usize,
+    pub units_consumed: usize,
+    pub units_suppressed: usize,
+    pub turret_shots: Vec<TurretShot>,
+    pub batteries_disabled: Vec<usize>,
+    pub spotters_disabled: usize,
+    pub base_health: Option<f32>,
+    pub objectives_held: Vec<bool>,
+    pub army_score: f32,
+    pub banked_resources: Vec<f32>,
+    pub wind: Coordinate,
+    pub impacts: Vec<Coordinate>,
+    pub schedule: Vec<ShotTiming>,
+}
+// Report definitions END
+//
 // Game definitions BEGIN
 #[derive(Debug)]
 pub struct Game {
-     pub map_radius: f32,
-     pub minimum_unit_radius: f32,
+     pub map: Map,
+     pub spawn_region: SpawnRegion,
      pub game_over: bool,
      pub turn_time: usize,
      pub target_radius: f32,
      pub base_coords: Coordinate,
      pub base_radius: f32,
      pub max_unit_range: f32,
+     pub unit_footprint: f32,
      pub max_resources: f32,
      pub units: Vec<Coordinate>,
      pub destinations: Vec<Coordinate>,
      pub targets: Vec<Coordinate>,
      pub target_costs: Vec<f32>,
+     pub target_munitions: Vec<Munition>,
+     pub target_batteries: Vec<usize>,
+     pub target_shapes: Vec<TargetShape>,
+     pub batteries: Vec<Coordinate>,
+     pub disabled_batteries: Vec<bool>,
+     pub health: Vec<f32>,
+     pub unit_kinds: Vec<UnitKind>,
+     pub camouflaged: Vec<bool>,
+     pub camouflage_orders: Vec<bool>,
+     pub raiding: Vec<bool>,
+     pub stances: Vec<Option<Stance>>,
+     pub fatigued: Vec<bool>,
+     pub routes: Vec<Vec<Coordinate>>,
+     pub objectives: Objectives,
+     pub objective_ticks: Vec<usize>,
+     pub objective_turns: Vec<usize>,
+     pub army_score: f32,
+     pub victory: VictoryCondition,
+     pub soldiers_lost: usize,
+     pub soldiers_consumed: usize,
+     pub siege: Option<Siege>,
+     pub base_health: Option<f32>,
+     pub suppression: Option<Suppression>,
+     pub defenses: Vec<Turret>,
+     pub smoke_clouds: Vec<Coordinate>,
+     pub turn: usize,
+     pub reinforcements: Option<ReinforcementSchedule>,
+     pub last_report: TurnReport,
+     pub economy: Economy,
+     pub banked_resources: Vec<f32>,
+     pub seed: u64,
+     pub rng: Rng,
+     pub dispersion: f32,
+     pub max_wind: f32,
+     pub wind: Coordinate,
+     pub flight_time: f32,
+     pub spotting_radius: Option<f32>,
+     pub sightings: Vec<Option<Sighting>>,
+     pub recon: Recon,
+     pub spotter_orders: Vec<Coordinate>,
+     pub spotters: Vec<Coordinate>,
+     pub counter_battery: CounterBattery,
+     pub stance_effects: StanceEffects,
 }
 
 /// This implementation is the full interface to interact with the Artillery game.
 /// It contains all of the logic to interact with and run the game.
+#[allow(unused)] // Compiler believes that Game's methods and attributes are unused
+                 // TODO: HIDING UNUSED, DEAD, OLD ETC. WARNINGS
 impl Game {
     /// `new` sets up the initial game state with these defaults:
-    /// - `map_radius` = 100.0 -> The default map is 100 units wide
-    /// - `minimum_unit_radius` = 90.0 -> Units must be placed on the outer edge
-    /// of the map
+    /// - `map` = a circle with a radius of 100.0 around 0,0 -> The default map is 100 units wide, and
+    ///   has no terrain
+    /// - `spawn_region` = 90.0 to 100.0 around 0,0 -> Units must be placed on the outer edge of the
+    ///   map
     /// - `turn_time` = 100 -> The default number of cycles per turn is 100. This value must match
-    /// `Game.max_resources` due to the way shot costs are calculated.
+    ///   `Game.max_resources` due to the way shot costs are calculated.
     /// - `target_radius` = 5.0 -> The default size of explosions is 5.0 units
     /// - `base_coords` = 0,0 -> The default base location is the center of the map
     /// - `base_radius` = 1.0 -> The default base is a circle with a diameter of 2.0 units
     /// - `max_unit_range` = 5.0 -> The default max range per turn for a soldier is 5.0 units
-    /// - `max_resources` = 100 -> The default resources for the artillery player is 100.0 per
-    /// turn. This value must match `Game.turn_time` due to the way shot costs are calculated.
+    /// - `unit_footprint` = 1.0 -> Units can't be placed, or move, within 1.0 units of each other
+    /// - `max_resources` = 100 -> The default resources for each artillery battery is 100.0 per
+    ///   turn. This value must match `Game.turn_time` due to the way shot costs are calculated.
+    /// - `batteries` = one at 0,0 -> The artillery player fires from a single battery at the base
+    /// - `reinforcements` = None -> The army does not receive reinforcements between turns
+    /// - `economy` = all zeroes -> Unspent resources are lost at the end of each turn
+    /// - `seed` = 0 -> The seed for all randomness in the game (see `Rng`)
+    /// - `dispersion` = 0.0 -> Shells land exactly on their target
+    /// - `max_wind` = 0.0 -> There is no wind
+    /// - `flight_time` = 0.0 -> Shells land on the same tick they are fired
+    /// - `spotting_radius` = None -> There is no fog of war; the artillery sees every unit outside
+    ///   of smoke
+    /// - `recon` = 20.0 cost, 20.0 radius -> Spotters cost as much as a short shot
+    /// - `counter_battery` = 5.0 range -> Raiding soldiers must end their move close to a battery
+    ///   or spotter to disable it
+    /// - `stance_effects` = 0.5 dig in radius, 2.0 sprint range, 1.5 sprint vulnerability -> Dug-in
+    ///   units are caught by half as much of a blast, and sprinting units move twice as far
+    /// - `objectives` = None -> The army can only win by reaching the base
+    /// - `victory` = None -> The game only ends when the army is destroyed, or reaches the base
+    /// - `siege` = None -> The base has no hit points; a single soldier captures it
+    /// - `suppression` = None -> Shells that miss have no effect
+    /// - `defenses` = none -> The base has no turrets
+    ///
+    /// Every unit starts with 1.0 health. A `Standard` shell deals 1.0 damage.
     pub fn new() -> Game {
         Game {
-            map_radius: 100.0, // Currently arbitrary
-            minimum_unit_radius: 90.0, // 90% of map_radius
+            map: Map::circle(Coordinate {x:0.0, y:0.0}, 100.0), // Currently arbitrary
+            spawn_region: SpawnRegion { center: Coordinate {x:0.0, y:0.0}, inner_radius: 90.0, outer_radius: 100.0 }, // 90% - 100% of the map's radius
             turn_time: 100, // MUST MATCH max_resources
             target_radius: 5.0, // Currently arbitrary
             base_coords: Coordinate {x:0.0, y:0.0}, // Currently arbitrary
             base_radius: 1.0, // Currently arbitrary
             max_unit_range: 5.0, // Currently arbitrary
+            unit_footprint: 1.0, // Same as a spotter's footprint
             max_resources: 100.0, // MUST BE `100.0` DUE TO SHOT COST FORMULA (see: fn shot_cost) 
             game_over: false,
             units: vec![],
             destinations: vec![],
             targets: vec![],
             target_costs: vec![],
+            target_munitions: vec![],
+            target_batteries: vec![],
+            target_shapes: vec![],
+            batteries: vec![Coordinate {x:0.0, y:0.0}], // One battery at the base
+            disabled_batteries: vec![false],
+            health: vec![],
+            unit_kinds: vec![],
+            camouflaged: vec![],
+            camouflage_orders: vec![],
+            raiding: vec![],
+            stances: vec![],
+            fatigued: vec![],
+            routes: vec![],
+            objectives: Objectives::default(),
+            objective_ticks: vec![],
+            objective_turns: vec![],
+            army_score: 0.0,
+            victory: VictoryCondition::default(),
+            soldiers_lost: 0,
+            soldiers_consumed: 0,
+            siege: None,
+            base_health: None,
+            suppression: None,
+            defenses: vec![],
+            smoke_clouds: vec![],
+            turn: 0,
+            reinforcements: None,
+            last_report: TurnReport::default(),
+            economy: Economy::default(),
+            banked_resources: vec![0.0],
+            seed: 0,
+            rng: Rng::new(0),
+            dispersion: 0.0,
+            max_wind: 0.0,
+            wind: Coordinate {x:0.0, y:0.0},
+            flight_time: 0.0,
+            spotting_radius: None,
+            sightings: vec![],
+            recon: Recon::default(),
+            spotter_orders: vec![],
+            spotters: vec![],
+            counter_battery: CounterBattery::default(),
+            stance_effects: StanceEffects::default(),
+        }
+    }
+
+    /// `with_map` accepts a `Map` (see `Map::load`), and sets up a game played on that map.
+    ///
+    /// The base is placed at the map's `base`, or at its origin if the map doesn't set one. Units
+    /// spawn in the map's `spawn_region`, or in the outer ring of the map (between 90% and 100% of
+    /// its radius around the origin) if the map doesn't set one. The artillery player has a battery
+    /// at the base, and one at each of the map's `outposts`. All other settings use the defaults
+    /// from `Game::new`.
+    ///
+    /// Returns the `Game`, or `ArtilleryError` on failure. Potential variants:
+    /// - MapError -> The map is invalid, or the base lies outside of it.
+    pub fn with_map(map: Map) -> Result<Game, ArtilleryError> {
+        if let Err(reason) = map.validate() {
+            return Err(ArtilleryError::map_error("with_map", &reason));
         }
+        let base_coords = map.base.clone().unwrap_or(map.origin.clone());
+        if !map.contains(&base_coords) {
+            return Err(ArtilleryError::map_error("with_map", "The base must lie inside the map"));
+        }
+        let mut game = Game::new();
+        game.spawn_region = map.spawn_region.clone().unwrap_or(
+            SpawnRegion { center: map.origin.clone(), inner_radius: map.radius() * 0.9, outer_radius: map.radius() }
+        );
+        game.batteries = std::iter::once(base_coords.clone()).chain(map.outposts.iter().cloned()).collect();
+        game.banked_resources = vec![0.0; game.batteries.len()];
+        game.disabled_batteries = vec![false; game.batteries.len()];
+        game.base_coords = base_coords;
+        game.map = map;
+        Ok(game)
     }
 // Game definitions END
 // 
 // adders BEGIN
-    /// `add_unit` accepts an `x` value and a `y` value as floats, and creates a unit at that location.
+    /// `add_unit` accepts an `x` value and a `y` value as floats, and a `kind`, and creates a unit
+    /// at that location.
     ///
     /// Automatically populates `self.units` with the `Coordinate`s, and sets
-    /// `self.destinations` to the same.
+    /// `self.destinations` to the same. The unit starts with full health.
     ///
     /// Units that are not given a destination can be thought of as moving to the coordinate they
     /// started at.
     ///
     /// Returns `()`, or `ArtilleryError` on failure. Potential variants:
-    /// - DistanceError -> A unit was placed too close to another.
-    pub fn add_unit(&mut self, x:f32, y:f32) -> Result<(), ArtilleryError>{
+    /// - DistanceError -> A unit was placed outside the spawn region, inside the base, or within
+    ///   `unit_footprint` of another unit.
+    /// - TerrainError -> A unit was placed inside impassable terrain.
+    pub fn add_unit(&mut self, x:f32, y:f32, kind: UnitKind) -> Result<(), ArtilleryError>{
         // Check if Coordinate is outside map:
         let temp_coord = Coordinate {x, y};
         if !self.is_in_map(&temp_coord) {
@@ -136,35 +932,98 @@ impl Game {
                                                               self.get_base_coords(), &temp_coord));
         }
         
-        // Check if unit is being placed too close to the base:
-        if temp_coord.distance(&self.get_base_coords()) < self.get_minimum_unit_radius() {
-            return Err(ArtilleryError::minimum_distance_error("add_unit", "place a unit too close to the base",
+        // Check if unit is being placed outside the spawn region:
+        let spawn_region = self.get_spawn_region();
+        if temp_coord.distance(&spawn_region.center) < spawn_region.inner_radius {
+            return Err(ArtilleryError::minimum_distance_error("add_unit", "place a unit outside the spawn region",
+                                                             &spawn_region.center, &temp_coord));
+        }
+        if temp_coord.distance(&spawn_region.center) > spawn_region.outer_radius {
+            return Err(ArtilleryError::maximum_distance_error("add_unit", "place a unit outside the spawn region",
+                                                             &spawn_region.center, &temp_coord));
+        }
+
+        // Check if unit is being placed inside the base:
+        if temp_coord.contains(self.get_base_coords(), self.get_base_radius()) {
+            return Err(ArtilleryError::minimum_distance_error("add_unit", "place a unit inside the base",
                                                              self.get_base_coords(), &temp_coord));
         }
 
+        // Check if unit is being placed inside impassable terrain:
+        if !self.is_passable(&temp_coord) {
+            return Err(ArtilleryError::terrain_error("add_unit", "place a unit", &temp_coord));
+        }
+
+        // Check if unit is being placed on top of another unit:
+        let footprint = self.get_unit_footprint();
+        if let Some(other) = self.get_units().iter().find(|unit| unit.distance(&temp_coord) < footprint) {
+            return Err(ArtilleryError::minimum_distance_error("add_unit", "place a unit too close to another",
+                                                             other, &temp_coord));
+        }
+
         // All checks succeeded, push the coordinates:
         self.get_units().push(Coordinate {x, y});
         self.get_destinations().push(Coordinate {x, y});
+        self.get_health().push(1.0);
+        self.get_unit_kinds().push(kind);
+        self.get_camouflaged().push(false);
+        self.get_camouflage_orders().push(false);
+        self.get_raiding().push(false);
+        self.get_stances().push(None);
+        self.get_fatigued().push(false);
+        self.get_routes().push(vec![]);
+        let sighting = self.is_spotted(&temp_coord).then_some(Sighting { position: temp_coord, age: 0 });
+        self.get_sightings().push(sighting);
         Ok(())
     }
 
-    /// `add_target` accepts an `x` value and `y` value as floats, and creates a target at that
-    /// location.
+    /// `add_target` accepts the index of the `battery` firing the shot, an `x` value and `y` value
+    /// as floats, a `munition`, and a `shape`, and creates a target at that location. Line and
+    /// creeping barrages start at that location (see `TargetShape`).
+    ///
+    /// The shot cost is calculated from the battery's previous target, or from the battery itself
+    /// for its first shot (see `shot_cost`), multiplied by the munition's `cost_multiplier`, and
+    /// paid for from that battery's resources.
     ///
     /// Returns `()`, or `ArtilleryError` on failure. Potential variants:
-    /// - DistanceError -> Target was placed outside the map
-    /// - ResourceError -> Player does not have enough free resources to place target.
-    pub fn add_target(&mut self, x:f32, y:f32) -> Result<(), ArtilleryError> {
+    /// - IndexError -> The battery does not exist
+    /// - DistanceError -> Target (or the end of a barrage) was placed outside the map
+    /// - OrderError -> A barrage starts and ends on the same point, its `width` isn't between
+    ///   `target_radius` and `TargetShape::MAX_WIDTH` times that, or a creeping barrage's
+    ///   `duration` isn't between 1 and `turn_time`
+    /// - ResourceError -> The battery does not have enough free resources to place target.
+    pub fn add_target(&mut self, battery: usize, x:f32, y:f32, munition: Munition, shape: TargetShape) -> Result<(), ArtilleryError> {
         let temp_coord = Coordinate {x, y};
+        // Check if the battery exists:
+        if battery >= self.get_batteries().len() {
+            return Err(ArtilleryError::index_error("add_target", battery));
+        }
         // Check if the target is outside the map:
         if !self.is_in_map(&temp_coord) {
             return Err(ArtilleryError::maximum_distance_error("add_target", "place a target outside the map", self.get_base_coords(), &temp_coord));
         }
+        // Check the barrage, if the target is one:
+        let end = shape.end(&temp_coord);
+        if !self.is_in_map(&end) {
+            return Err(ArtilleryError::maximum_distance_error("add_target", "end a barrage outside the map", self.get_base_coords(), &end));
+        }
+        let max_width = self.target_radius * TargetShape::MAX_WIDTH;
+        match shape {
+            TargetShape::Line { .. } | TargetShape::Creeping { .. } if shape.length(&temp_coord) == 0.0 => {
+                return Err(ArtilleryError::order_error("add_target", "a barrage can't start and end on the same point"));
+            }
+            TargetShape::Line { width, .. } | TargetShape::Creeping { width, .. } if !(self.target_radius..=max_width).contains(&width) => {
+                return Err(ArtilleryError::order_error("add_target", &format!("a barrage's `width` must be between {} and {}", self.target_radius, max_width)));
+            }
+            TargetShape::Creeping { duration, .. } if duration == 0 || duration > self.turn_time => {
+                return Err(ArtilleryError::order_error("add_target", &format!("a creeping barrage's `duration` must be between 1 and {}", self.turn_time)));
+            }
+            _ => {}
+        }
 
-        // Check if player 2 is out of resources:
-        let shot_cost = self.shot_cost(&temp_coord);
-        let temp_cost: f32 = self.get_target_costs().to_owned().into_iter().sum();
-        let available_resources = self.get_max_resources() - temp_cost;
+        // Check if the battery is out of resources:
+        let shot_cost = self.shot_cost(&temp_coord, &shape, battery) * munition.cost_multiplier();
+        let available_resources = self.get_available_resources(battery);
         if shot_cost > available_resources {
             return Err(ArtilleryError::resource_error("add_target", format!("place a target. Cost: {shot_cost} Available: {available_resources}").as_str()));
         }
@@ -172,6 +1031,37 @@ impl Game {
         // Add the target, and add the shot cost:
         self.get_targets().push(Coordinate {x, y});
         self.get_target_costs().push(shot_cost);
+        self.get_target_munitions().push(munition);
+        self.get_target_batteries().push(battery);
+        self.get_target_shapes().push(shape);
+        Ok(())
+    }
+
+    /// `add_spotter` accepts an `x` value and `y` value as floats, and orders a spotter to be
+    /// placed at that location.
+    ///
+    /// Spotters are paid for from the resources of the battery at the base (battery 0). They are
+    /// placed at the end of the turn, and reveal units within `recon.spotter_radius` until the end of the following turn,
+    /// unless a unit moves over them first (see `run_turn`).
+    ///
+    /// Returns `()`, or `ArtilleryError` on failure. Potential variants:
+    /// - DistanceError -> Spotter was placed outside the map
+    /// - ResourceError -> Player does not have enough free resources to place the spotter.
+    pub fn add_spotter(&mut self, x:f32, y:f32) -> Result<(), ArtilleryError> {
+        let temp_coord = Coordinate {x, y};
+        // Check if the spotter is outside the map:
+        if !self.is_in_map(&temp_coord) {
+            return Err(ArtilleryError::maximum_distance_error("add_spotter", "place a spotter outside the map", self.get_base_coords(), &temp_coord));
+        }
+
+        // Check if player 2 is out of resources:
+        let spotter_cost = self.get_recon().spotter_cost;
+        let available_resources = self.get_available_resources(0);
+        if spotter_cost > available_resources {
+            return Err(ArtilleryError::resource_error("add_spotter", format!("place a spotter. Cost: {spotter_cost} Available: {available_resources}").as_str()));
+        }
+
+        self.get_spotter_orders().push(temp_coord);
         Ok(())
     }
 // adders END
@@ -187,30 +1077,53 @@ impl Game {
             Some(_) => {
                 units.remove(index);
                 self.get_destinations().remove(index);
+                self.get_health().remove(index);
+                self.get_unit_kinds().remove(index);
+                self.get_camouflaged().remove(index);
+                self.get_camouflage_orders().remove(index);
+                self.get_raiding().remove(index);
+                self.get_stances().remove(index);
+                self.get_fatigued().remove(index);
+                self.get_routes().remove(index);
+                self.get_sightings().remove(index);
                 Ok(())
             }
         }
     }
 
     /// `remove_target` removes the last created `target` from `self.targets`, and the associated
-    /// shot cost from `self.target_costs`.
+    /// shot cost, munition, battery and shape from `self.target_costs`, `self.target_munitions`,
+    /// `self.target_batteries` and `self.target_shapes`.
     ///
     /// Removing by index could be very complex in some cases becauses target costs are dependent
-    /// on the distance from the base/previous target. To keep it simple, we only allow popping
+    /// on the distance from the battery/previous target. To keep it simple, we only allow popping
     /// these elements from their vectors.
     ///
     /// Returns an `IndexError` if there are no targets to pop.
     pub fn remove_newest_target(&mut self) -> Result<(), ArtilleryError> {
         let targets = self.get_targets();
-        match targets.get(0) { // Check index 0 for a target to pop
+        match targets.first() { // Check index 0 for a target to pop
             None => Err(ArtilleryError::index_error("remove_target", 0)),
             Some(_) => {
                 let _ = targets.pop();
                 let _ = self.get_target_costs().pop();
+                let _ = self.get_target_munitions().pop();
+                let _ = self.get_target_batteries().pop();
+                let _ = self.get_target_shapes().pop();
                 Ok(())
             }
         }
     }
+
+    /// `remove_newest_spotter` cancels the most recent spotter order, refunding its cost.
+    ///
+    /// Returns an `IndexError` if there are no spotter orders to cancel.
+    pub fn remove_newest_spotter(&mut self) -> Result<(), ArtilleryError> {
+        match self.get_spotter_orders().pop() {
+            None => Err(ArtilleryError::index_error("remove_spotter", 0)),
+            Some(_) => Ok(()),
+        }
+    }
 // removers END
 //
 // getters BEGIN
@@ -253,129 +1166,787 @@ impl Game {
         &mut self.target_costs
     }
 
-    /// `get_destination` accepts an `index` value, and returns a Coordinate for that unit. This
-    /// `Coordinate` represents a unit's current destination.
+    /// `get_target_munition` accepts an `index` value, and returns the `Munition` for that target.
     ///
-    /// Returns an `IndexError` if a destination does not exist.
-    pub fn get_destination(&mut self, index: usize) -> Result<&Coordinate, ArtilleryError> {
-        match self.get_destinations().get(index) {
-            None => Err(ArtilleryError::index_error("get_destination", index)),
-            Some(unit) => Ok(unit)
+    /// Returns an `IndexError` if a target does not exist.
+    pub fn get_target_munition(&mut self, index:usize) -> Result<&Munition, ArtilleryError> {
+        match self.get_target_munitions().get(index) {
+            None => Err(ArtilleryError::index_error("get_target_munition", index)),
+            Some(munition) => Ok(munition)
         }
     }
 
-    /// `get_destinations` returns a vector of coordinates. Each `Coordinate` represents a unit's current
-    /// destination.
+    /// `get_target_munitions` returns a vector of `Munition`s, one for each artillery shot.
     ///
     /// Should never fail. Useful if the underlying `Game` struct ever changes.
-    pub fn get_destinations(&mut self) -> &mut Vec<Coordinate> {
-        &mut self.destinations
+    pub fn get_target_munitions(&mut self) -> &mut Vec<Munition> {
+        &mut self.target_munitions
     }
 
-    /// `get_target` accepts an `index` value, and returns a `Coordinate` for that target. This
-    /// `Coordinate` represents an artillery target.
+    /// `get_target_batteries` returns a vector of battery indexes. Each index is the battery that
+    /// fires at the target with the same index in `self.targets`.
     ///
-    /// Returns an `IndexError` if a target does not exist.
-    pub fn get_target(&mut self, index:usize) -> Result<&Coordinate, ArtilleryError> {
-        match self.get_targets().get(index) {
-            None => Err(ArtilleryError::index_error("get_destination", index)),
-            Some(target) => Ok(target)
-        }
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_target_batteries(&mut self) -> &mut Vec<usize> {
+        &mut self.target_batteries
     }
 
-    /// `get_targets` returns a vector of coordinates. Each `Coordinate` represents an
-    /// artillery target.
+    /// `get_target_shapes` returns a vector of `TargetShape`s. Each shape is the area covered by
+    /// the target with the same index in `self.targets`.
     ///
     /// Should never fail. Useful if the underlying `Game` struct changes.
-    pub fn get_targets(&mut self) -> &mut Vec<Coordinate> {
-        &mut self.targets
+    pub fn get_target_shapes(&mut self) -> &mut Vec<TargetShape> {
+        &mut self.target_shapes
     }
 
-    /// `get_base_coords` returns a reference to a `Coordinate`. This `Coordinate` represents the
-    /// location of the artillery player's base.
+    /// `get_batteries` returns the position of each of the artillery player's batteries. Battery 0
+    /// is the battery at the base.
     ///
     /// Should never fail. Useful if the underlying `Game` struct changes.
-    pub fn get_base_coords(&self) -> &Coordinate {
-        &self.base_coords
+    pub fn get_batteries(&self) -> &Vec<Coordinate> {
+        &self.batteries
     }
 
-    /// `get_base_radius` returns the radius of the base.
+    /// `get_disabled_batteries` returns a vector of booleans. Each boolean is true if that battery
+    /// was disabled by a raid, and receives no `max_resources` this turn (see `set_raid`).
     ///
     /// Should never fail. Useful if the underlying `Game` struct changes.
-    pub fn get_base_radius(&self) -> f32 {
-        self.base_radius
+    pub fn get_disabled_batteries(&self) -> &Vec<bool> {
+        &self.disabled_batteries
     }
 
-    /// `get_map_radius` returns the radius of the map.
+    /// `get_counter_battery` returns the range of the army's raids (see `set_raid`).
     ///
     /// Should never fail. Useful if the underlying `Game` struct changes.
-    pub fn get_map_radius(&self) -> f32 {
-        self.map_radius
+    pub fn get_counter_battery(&self) -> &CounterBattery {
+        &self.counter_battery
     }
 
-    /// `get_max_unit_range` returns the range of units.
+    /// `get_stance_effects` returns the effects of each unit stance (see `set_stance`).
     ///
     /// Should never fail. Useful if the underlying `Game` struct changes.
-    pub fn get_max_unit_range(&self) -> f32 {
-        self.max_unit_range
+    pub fn get_stance_effects(&self) -> &StanceEffects {
+        &self.stance_effects
     }
 
-    /// `get_max_resources` returns the max resources for the artillery player.
+    /// `get_health` returns a vector of floats. Each float represents a unit's remaining health.
+    /// Units are destroyed once their health reaches 0.0.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct ever changes.
+    pub fn get_health(&mut self) -> &mut Vec<f32> {
+        &mut self.health
+    }
+
+    /// `get_unit_kinds` returns a vector with the `UnitKind` of every unit.
     ///
     /// Should never fail. Useful if the underlying `Game` struct changes.
-    pub fn get_max_resources(&self) -> f32 {
-        self.max_resources
+    pub fn get_unit_kinds(&mut self) -> &mut Vec<UnitKind> {
+        &mut self.unit_kinds
     }
 
-    /// `get_target_radius` returns the radius for each target for the artillery player.
+    /// `get_routes` returns a vector with an entry for every unit: the waypoints it will follow
+    /// after reaching its current destination (see `set_destination`). Units without a route have
+    /// an empty list.
     ///
     /// Should never fail. Useful if the underlying `Game` struct changes.
-    pub fn get_target_radius(&self) -> f32 {
-        self.target_radius
+    pub fn get_routes(&mut self) -> &mut Vec<Vec<Coordinate>> {
+        &mut self.routes
     }
 
-    /// `get_game_over` returns the state of the game.
-    /// **False** means the game is running, *True* means the game is over
+    /// `get_camouflaged` returns a vector of booleans. Each boolean is true if that unit is
+    /// currently hidden by camouflage (see `set_camouflage`).
     ///
     /// Should never fail. Useful if the underlying `Game` struct changes.
-    pub fn get_game_over(&self) -> bool {
-        self.game_over
+    pub fn get_camouflaged(&mut self) -> &mut Vec<bool> {
+        &mut self.camouflaged
     }
 
-    /// `get_minimum_unit_radius` returns the minimum distance from the base that a new unit must
-    /// be placed.
-    pub fn get_minimum_unit_radius(&self) -> f32 {
-        self.minimum_unit_radius
+    /// `get_camouflage_orders` returns a vector of booleans. Each boolean is true if that unit was
+    /// ordered to camouflage this turn, and so stays hidden through the following turn (see
+    /// `set_camouflage`).
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_camouflage_orders(&mut self) -> &mut Vec<bool> {
+        &mut self.camouflage_orders
     }
-// getters END
-//
-// setters BEGIN
-    /// `set_destination` accepts an `index`, `x`, and `y`, value, and updates the corresponding
-    /// destination contained in `self.destinations`.
+
+    /// `get_raiding` returns a vector of booleans. Each boolean is true if that unit has been
+    /// ordered to raid this turn (see `set_raid`).
     ///
-    /// *Destinations are never removed, they can only be reset.*
-    pub fn set_destination(&mut self, index:usize, x:f32, y:f32) -> Result<(), ArtilleryError> {
-        // Check if unit exists; return early if false
-        if let Err(_) = self.get_unit(index) {
-            return Err(ArtilleryError::index_error("set_destination", index));
-        }
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_raiding(&mut self) -> &mut Vec<bool> {
+        &mut self.raiding
+    }
+
+    /// `get_stances` returns a vector with an entry for every unit: its stance this turn, or None
+    /// if it has no stance (see `set_stance`).
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_stances(&mut self) -> &mut Vec<Option<Stance>> {
+        &mut self.stances
+    }
+
+    /// `get_fatigued` returns a vector of booleans. Each boolean is true if that unit sprinted
+    /// last turn, and takes extra damage this turn (see `StanceEffects`).
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_fatigued(&mut self) -> &mut Vec<bool> {
+        &mut self.fatigued
+    }
+
+    /// `get_smoke_clouds` returns a vector of coordinates. Each `Coordinate` is the center of a
+    /// smoke cloud left over from the last turn. Smoke clouds have a radius of twice
+    /// `target_radius`.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct ever changes.
+    pub fn get_smoke_clouds(&mut self) -> &mut Vec<Coordinate> {
+        &mut self.smoke_clouds
+    }
+
+    /// `get_visible_units` returns the positions of all units the artillery player can see (see
+    /// `is_unit_spotted`). Decoys are included, and can't be told apart from soldiers.
+    ///
+    /// Should never fail.
+    pub fn get_visible_units(&mut self) -> Vec<Coordinate> {
+        self.get_visible_unit_indexes()
+            .into_iter()
+            .map(|index| self.units[index].clone())
+            .collect()
+    }
+
+    /// `get_visible_unit_indexes` returns the index of every unit the artillery player can see
+    /// (see `is_unit_spotted`), in order.
+    ///
+    /// Should never fail.
+    pub fn get_visible_unit_indexes(&self) -> Vec<usize> {
+        (0..self.units.len())
+            .filter(|index| self.is_unit_spotted(*index))
+            .collect()
+    }
+
+    /// `get_visible_unit` accepts an `index` into the list of units the artillery player can see
+    /// (see `get_visible_unit_indexes`), and returns that unit's index in `self.units`.
+    ///
+    /// The artillery player never learns a unit's real index, so it can't count hidden units by
+    /// probing for them.
+    ///
+    /// Returns an `IndexError` if the artillery player can see fewer units than that.
+    pub fn get_visible_unit(&self, index:usize) -> Result<usize, ArtilleryError> {
+        self.get_visible_unit_indexes()
+            .get(index)
+            .copied()
+            .ok_or_else(|| ArtilleryError::index_error("get_visible_unit", index))
+    }
+
+    /// `get_last_known_units` returns a `Sighting` for every unit the artillery player can't see
+    /// right now, but has spotted at the end of an earlier turn. Units that have never been
+    /// spotted are left out.
+    ///
+    /// Should never fail.
+    pub fn get_last_known_units(&self) -> Vec<Sighting> {
+        (0..self.units.len())
+            .filter(|index| !self.is_unit_spotted(*index))
+            .filter_map(|index| self.sightings[index].clone())
+            .collect()
+    }
+
+    /// `get_sightings` returns a vector with an entry for every unit: the last time the artillery
+    /// player spotted it, or `None` if it has never been spotted.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_sightings(&mut self) -> &mut Vec<Option<Sighting>> {
+        &mut self.sightings
+    }
+
+    /// `get_spotting_radius` returns how far from the base the artillery player can see units, or
+    /// `None` if there is no fog of war.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_spotting_radius(&self) -> Option<f32> {
+        self.spotting_radius
+    }
+
+    /// `get_objectives` returns the capture points on the map, and the rules for winning with them.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_objectives(&self) -> &Objectives {
+        &self.objectives
+    }
+
+    /// `get_objective_ticks` returns, for every objective, the number of ticks in a row it has been
+    /// held by the army.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_objective_ticks(&self) -> &Vec<usize> {
+        &self.objective_ticks
+    }
+
+    /// `get_objective_turns` returns, for every objective, the number of turns in a row it has been
+    /// held by the army at the end of the turn.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_objective_turns(&self) -> &Vec<usize> {
+        &self.objective_turns
+    }
+
+    /// `get_army_score` returns the points the army has earned by holding objectives.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_army_score(&self) -> f32 {
+        self.army_score
+    }
+
+    /// `get_victory` returns the optional ways the game can end (see `VictoryCondition`).
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_victory(&self) -> &VictoryCondition {
+        &self.victory
+    }
+
+    /// `get_soldiers_lost` returns the number of soldiers destroyed by the artillery so far.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_soldiers_lost(&self) -> usize {
+        self.soldiers_lost
+    }
+
+    /// `get_soldiers_consumed` returns the number of soldiers consumed by a siege so far (see
+    /// `Siege`).
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_soldiers_consumed(&self) -> usize {
+        self.soldiers_consumed
+    }
+
+    /// `get_siege` returns the base's hit points and the damage each soldier deals to it, if the
+    /// base can withstand a siege (see `Siege`).
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_siege(&self) -> &Option<Siege> {
+        &self.siege
+    }
+
+    /// `get_base_health` returns the base's remaining hit points, or None if the base can't
+    /// withstand a siege.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_base_health(&self) -> Option<f32> {
+        self.base_health
+    }
+
+    /// `get_suppression` returns how shells pin down the units they miss, if they do (see
+    /// `Suppression`).
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_suppression(&self) -> &Option<Suppression> {
+        &self.suppression
+    }
+
+    /// `get_defenses` returns the turrets protecting the base (see `Turret`).
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_defenses(&self) -> &Vec<Turret> {
+        &self.defenses
+    }
+
+    /// `get_destination` accepts an `index` value, and returns a Coordinate for that unit. This
+    /// `Coordinate` represents a unit's current destination.
+    ///
+    /// Returns an `IndexError` if a destination does not exist.
+    pub fn get_destination(&mut self, index: usize) -> Result<&Coordinate, ArtilleryError> {
+        match self.get_destinations().get(index) {
+            None => Err(ArtilleryError::index_error("get_destination", index)),
+            Some(unit) => Ok(unit)
+        }
+    }
+
+    /// `get_destinations` returns a vector of coordinates. Each `Coordinate` represents a unit's current
+    /// destination.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct ever changes.
+    pub fn get_destinations(&mut self) -> &mut Vec<Coordinate> {
+        &mut self.destinations
+    }
+
+    /// `get_target` accepts an `index` value, and returns a `Coordinate` for that target. This
+    /// `Coordinate` represents an artillery target.
+    ///
+    /// Returns an `IndexError` if a target does not exist.
+    pub fn get_target(&mut self, index:usize) -> Result<&Coordinate, ArtilleryError> {
+        match self.get_targets().get(index) {
+            None => Err(ArtilleryError::index_error("get_destination", index)),
+            Some(target) => Ok(target)
+        }
+    }
+
+    /// `get_targets` returns a vector of coordinates. Each `Coordinate` represents an
+    /// artillery target.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_targets(&mut self) -> &mut Vec<Coordinate> {
+        &mut self.targets
+    }
+
+    /// `get_base_coords` returns a reference to a `Coordinate`. This `Coordinate` represents the
+    /// location of the artillery player's base.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_base_coords(&self) -> &Coordinate {
+        &self.base_coords
+    }
+
+    /// `get_base_radius` returns the radius of the base.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_base_radius(&self) -> f32 {
+        self.base_radius
+    }
+
+    /// `get_map_radius` returns the distance from the map's origin to its farthest point. For
+    /// the default circular map, this is the map's radius.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_map_radius(&self) -> f32 {
+        self.get_map().radius()
+    }
+
+    /// `get_map` returns the map the game is played on.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_map(&self) -> &Map {
+        &self.map
+    }
+
+    /// `get_max_unit_range` returns the range of units.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_max_unit_range(&self) -> f32 {
+        self.max_unit_range
+    }
+
+    /// `get_unit_footprint` returns the space each unit takes up: the closest that two units can
+    /// stand to each other.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_unit_footprint(&self) -> f32 {
+        self.unit_footprint
+    }
+
+    /// `get_max_resources` returns the max resources for the artillery player.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_max_resources(&self) -> f32 {
+        self.max_resources
+    }
+
+    /// `get_banked_resources` returns the resources each battery carried over from previous turns.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_banked_resources(&self) -> &Vec<f32> {
+        &self.banked_resources
+    }
+
+    /// `get_available_resources` accepts the index of a `battery`, and returns the resources it can
+    /// still spend this turn: `max_resources` plus its banked resources, minus the cost of every
+    /// target it fires at. Ordered spotters are paid for by battery 0. Batteries disabled by a raid
+    /// don't receive `max_resources`, but can still spend their banked resources.
+    ///
+    /// Should never fail, as long as the battery exists.
+    pub fn get_available_resources(&mut self, battery: usize) -> f32 {
+        let spent: f32 = self.target_costs
+            .iter()
+            .zip(self.target_batteries.iter())
+            .filter(|(_, target_battery)| **target_battery == battery)
+            .map(|(cost, _)| cost)
+            .sum();
+        let spotters_cost = if battery == 0 { self.get_spotter_orders().len() as f32 * self.get_recon().spotter_cost } else { 0.0 };
+        let max_resources = if self.disabled_batteries[battery] { 0.0 } else { self.get_max_resources() };
+        max_resources + self.get_banked_resources()[battery] - spent - spotters_cost
+    }
+
+    /// `get_all_available_resources` returns the resources each battery can still spend this turn
+    /// (see `get_available_resources`).
+    ///
+    /// Should never fail.
+    pub fn get_all_available_resources(&mut self) -> Vec<f32> {
+        (0..self.get_batteries().len()).map(|battery| self.get_available_resources(battery)).collect()
+    }
+
+    /// `get_recon` returns the cost and reach of the artillery player's spotters.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_recon(&self) -> &Recon {
+        &self.recon
+    }
+
+    /// `get_spotter_orders` returns a vector of coordinates. Each `Coordinate` is a spotter
+    /// ordered this turn, which will be placed at the end of the turn.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_spotter_orders(&mut self) -> &mut Vec<Coordinate> {
+        &mut self.spotter_orders
+    }
+
+    /// `get_spotters` returns a vector of coordinates. Each `Coordinate` is a spotter that is
+    /// currently revealing units.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_spotters(&mut self) -> &mut Vec<Coordinate> {
+        &mut self.spotters
+    }
+
+    /// `get_economy` returns the rules for carrying resources over between turns.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_economy(&self) -> &Economy {
+        &self.economy
+    }
+
+    /// `get_seed` returns the seed that all of the game's randomness is drawn from.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// `get_dispersion` returns how far a shell may stray from its target, per unit of distance
+    /// between the target and the base.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_dispersion(&self) -> f32 {
+        self.dispersion
+    }
+
+    /// `get_max_wind` returns the strongest wind that can be drawn for a turn.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_max_wind(&self) -> f32 {
+        self.max_wind
+    }
+
+    /// `get_wind` returns the wind for the current turn. Every shell is pushed by this vector.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_wind(&self) -> &Coordinate {
+        &self.wind
+    }
+
+    /// `get_flight_time` returns the number of ticks a shell spends in the air for every unit of
+    /// distance between its target and the base.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_flight_time(&self) -> f32 {
+        self.flight_time
+    }
+
+    /// `get_target_schedule` returns a `ShotTiming` for each target in `self.targets`.
+    ///
+    /// Each battery fires its own chain of shots, alongside the other batteries. Each shot's cost
+    /// is rounded down to a whole number of ticks. A battery's first shot is fired on tick 0, and
+    /// every following shot is fired once the costs of all of that battery's previous shots have
+    /// elapsed. Each shell then lands after `flight_time` ticks for every unit of distance between
+    /// its target and its battery (rounded down), and `DelayedFuse` shells wait a further
+    /// `FUSE_DELAY` ticks. Shots that would be fired or land after the end of the turn (possible
+    /// once banked resources push the total cost above `turn_time`) do so on the final tick
+    /// instead.
+    ///
+    /// Should never fail.
+    pub fn get_target_schedule(&mut self) -> Vec<ShotTiming> {
+        let last_tick = self.turn_time.saturating_sub(1);
+        let batteries = self.get_batteries().clone();
+        let flight_time = self.get_flight_time();
+        let munitions = self.get_target_munitions().clone();
+        let targets = self.get_targets().clone();
+        let target_batteries = self.get_target_batteries().clone();
+        let mut elapsed = vec![0; batteries.len()]; // Each battery's chain is timed separately
+        let mut schedule = vec![];
+        for (index, target_cost) in self.get_target_costs().iter().enumerate() {
+            let battery = target_batteries[index];
+            let mut delay = (flight_time * batteries[battery].distance(&targets[index])).floor() as usize;
+            if munitions[index] == Munition::DelayedFuse {
+                delay = delay.saturating_add(Munition::FUSE_DELAY);
+            }
+            let fire_tick = elapsed[battery].min(last_tick);
+            schedule.push(ShotTiming { fire_tick, impact_tick: fire_tick.saturating_add(delay).min(last_tick) });
+            elapsed[battery] += target_cost.floor() as usize;
+        }
+        schedule
+    }
+
+    /// `get_terrain` returns every feature on the map, such as rocks and cover zones.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_terrain(&self) -> &Vec<TerrainFeature> {
+        &self.get_map().terrain
+    }
+
+    /// `get_target_radius` returns the radius for each target for the artillery player.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_target_radius(&self) -> f32 {
+        self.target_radius
+    }
+
+    /// `get_game_over` returns the state of the game.
+    /// **False** means the game is running, *True* means the game is over
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_game_over(&self) -> bool {
+        self.game_over
+    }
+
+    /// `get_minimum_unit_radius` returns the minimum distance from the center of the spawn region
+    /// that a new unit must be placed.
+    pub fn get_minimum_unit_radius(&self) -> f32 {
+        self.get_spawn_region().inner_radius
+    }
+
+    /// `get_spawn_region` returns the ring where new units may be placed.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_spawn_region(&self) -> &SpawnRegion {
+        &self.spawn_region
+    }
+
+    /// `get_turn` returns the number of turns that have been played.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_turn(&self) -> usize {
+        self.turn
+    }
+
+    /// `get_reinforcements` returns the reinforcement schedule for the army player, if any.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_reinforcements(&self) -> &Option<ReinforcementSchedule> {
+        &self.reinforcements
+    }
+
+    /// `get_last_report` returns the `TurnReport` for the most recently played turn.
+    ///
+    /// Should never fail. Useful if the underlying `Game` struct changes.
+    pub fn get_last_report(&self) -> &TurnReport {
+        &self.last_report
+    }
+// getters END
+//
+// setters BEGIN
+    /// `set_destination` accepts an `index`, `x`, and `y`, value, and updates the corresponding
+    /// destination contained in `self.destinations`.
+    ///
+    /// Units move in a straight line, so the path from the unit to its destination must stay inside
+    /// the map, and must not cross impassable terrain. Climbing uses up extra range (see
+    /// `Map::path_cost`).
+    ///
+    /// Points that are too far away, or hidden behind obstacles, are reached by a route (see
+    /// `navigation::find_path`). This turn's destination is as far along the first leg of the route
+    /// as the unit's range allows, and the rest of the route is followed on later turns (see
+    /// `advance_routes`).
+    ///
+    /// Giving a camouflaged unit somewhere else to go removes its camouflage.
+    ///
+    /// A sprinting unit's range is multiplied by `stance_effects.sprint_range`, and units that are
+    /// holding or dug in can't be given anywhere else to go (see `set_stance`).
+    ///
+    /// *Destinations are never removed, they can only be reset.*
+    ///
+    /// Returns the full route from the unit to the point, or `ArtilleryError` on failure. Potential
+    /// variants:
+    /// - IndexError -> The unit does not exist.
+    /// - DistanceError -> The point is outside of the map.
+    /// - TerrainError -> The point is inside impassable terrain, or no route reaches it.
+    /// - OrderError -> The unit is holding or dug in this turn.
+    pub fn set_destination(&mut self, index:usize, x:f32, y:f32) -> Result<Vec<Coordinate>, ArtilleryError> {
+        // Check if unit exists; return early if false
+        if self.get_unit(index).is_err() {
+            return Err(ArtilleryError::index_error("set_destination", index));
+        }
 
         // Check if Coordinate falls outside of map; return early if true
-        let temp_coord = Coordinate {x:x, y:y};
-        if temp_coord.distance(self.get_base_coords()) > self.get_map_radius() {
+        let temp_coord = Coordinate {x, y};
+        if !self.is_in_map(&temp_coord) {
             return Err(ArtilleryError::maximum_distance_error("set_destination", "set a unit's destination outside of the map", &temp_coord, &self.base_coords));
         }
 
-        // Check if Coordinate falls outside of units range; return early if true
-        if temp_coord.distance(self.get_unit(index)?) > self.get_max_unit_range() {
-            return Err(ArtilleryError::maximum_distance_error("set_destination", "set a unit's destination beyond their maximum range", &temp_coord, self.get_unit(index)?));
+        // Check if Coordinate falls inside impassable terrain; return early if true
+        if !self.is_passable(&temp_coord) {
+            return Err(ArtilleryError::terrain_error("set_destination", "set a unit's destination", &temp_coord));
+        }
+
+        // Check if the unit's stance keeps it in place; return early if true
+        let position = self.get_unit(index)?.clone();
+        let stationary = matches!(self.get_stances()[index], Some(Stance::Hold | Stance::DigIn));
+        if stationary && temp_coord.distance(&position) > 0.0 {
+            return Err(ArtilleryError::order_error("set_destination", "a unit that is holding or dug in can't move this turn"));
+        }
+
+        // Find a route to the Coordinate; return early if there is none
+        let Some(path) = navigation::find_path(self.get_map(), &position, &temp_coord) else {
+            return Err(ArtilleryError::terrain_error("set_destination", "find a route to a unit's destination", &temp_coord));
+        };
+
+        // Checks complete; moving units can't stay camouflaged
+        if temp_coord.distance(&position) > 0.0 {
+            self.get_camouflaged()[index] = false;
+            self.get_camouflage_orders()[index] = false;
+        }
+        let (destination, route) = navigation::clip_path(self.get_map(), &path, self.unit_range(index));
+        self.get_destinations()[index] = destination;
+        self.get_routes()[index] = route;
+        Ok(path)
+    }
+
+    /// `set_group_destination` accepts a list of unit `indexes`, a destination `x` and `y`, a
+    /// `formation`, and the `spacing` between units, and routes every unit to its own slot in the
+    /// formation around the destination.
+    ///
+    /// The formation faces from the middle of the group towards the destination (see
+    /// `Formation::offsets`), and units are given slots in the order they are listed. Each unit is
+    /// then routed to its slot with `set_destination`, so it only gets as far as its own range
+    /// allows this turn, and follows its route on later turns.
+    ///
+    /// Units that can't be sent to their slot (for example, because they don't exist, they are
+    /// dug in, or their slot lies outside the map) keep their previous orders, and are reported
+    /// with the error from `set_destination`.
+    ///
+    /// Returns every unit's `GroupMove`, or `ArtilleryError` on failure. Potential variants:
+    /// - OrderError -> No units were listed, more units were listed than there are, a unit was
+    ///   listed twice, or `spacing` isn't positive.
+    pub fn set_group_destination(&mut self, indexes:&[usize], x:f32, y:f32, formation:Formation, spacing:f32) -> Result<Vec<GroupMove>, ArtilleryError> {
+        if indexes.is_empty() {
+            return Err(ArtilleryError::order_error("set_group_destination", "a group order must list at least one unit"));
+        }
+        if indexes.len() > self.get_units().len() {
+            return Err(ArtilleryError::order_error("set_group_destination", "a group order can't list more units than there are"));
+        }
+        let mut listed = HashSet::with_capacity(indexes.len());
+        if !indexes.iter().all(|index| listed.insert(*index)) {
+            return Err(ArtilleryError::order_error("set_group_destination", "a group order can't list a unit twice"));
+        }
+        if spacing <= 0.0 {
+            return Err(ArtilleryError::order_error("set_group_destination", "a group order's `spacing` must be positive"));
+        }
+
+        // Face from the middle of the group towards the destination, or up the map if it's
+        // already there
+        let members: Vec<Coordinate> = indexes.iter().filter_map(|index| self.get_units().get(*index).cloned()).collect();
+        let middle = Coordinate {
+            x: members.iter().map(|unit| unit.x).sum::<f32>() / members.len().max(1) as f32,
+            y: members.iter().map(|unit| unit.y).sum::<f32>() / members.len().max(1) as f32,
+        };
+        let goal = Coordinate { x, y };
+        let distance = middle.distance(&goal);
+        let forward = if members.is_empty() || distance == 0.0 {
+            Coordinate { x: 0.0, y: 1.0 }
         }
+        else {
+            Coordinate { x: (goal.x - middle.x) / distance, y: (goal.y - middle.y) / distance }
+        };
 
-        // Checks complete
-        self.get_destinations()[index] = temp_coord;
+        let offsets = formation.offsets(indexes.len(), spacing);
+        let mut moves = vec![];
+        for (index, offset) in indexes.iter().zip(offsets) {
+            let slot = Coordinate {
+                x: goal.x + offset.x * forward.y + offset.y * forward.x,
+                y: goal.y - offset.x * forward.x + offset.y * forward.y,
+            };
+            let (destination, error) = match self.set_destination(*index, slot.x, slot.y) {
+                Ok(_) => (Some(self.get_destinations()[*index].clone()), None),
+                Err(error) => (None, Some(error)),
+            };
+            moves.push(GroupMove { unit: *index, slot, destination, error });
+        }
+        Ok(moves)
+    }
+
+    /// `set_camouflage` accepts an `index`, and camouflages that unit for the rest of this turn and
+    /// all of the next.
+    ///
+    /// Only stationary units can hide, so the unit's destination is reset to its current position.
+    /// A camouflaged unit is hidden from the artillery player, even from the base and spotters,
+    /// until the following turn has been played (see `update_sightings`), unless it is given
+    /// somewhere else to go.
+    ///
+    /// Returns an `IndexError` if the unit does not exist.
+    pub fn set_camouflage(&mut self, index:usize) -> Result<(), ArtilleryError> {
+        let position = match self.get_unit(index) {
+            Err(_) => return Err(ArtilleryError::index_error("set_camouflage", index)),
+            Ok(position) => position.clone(),
+        };
+        self.get_destinations()[index] = position;
+        self.get_routes()[index].clear();
+        self.get_camouflaged()[index] = true;
+        self.get_camouflage_orders()[index] = true;
+        Ok(())
+    }
+
+    /// `set_stance` accepts an `index` and a `stance`, and gives that unit the stance for the rest of
+    /// the turn. A stance of None clears it.
+    ///
+    /// The unit's orders are updated to match (see `Stance`):
+    /// - `Hold` resets the unit's destination to its current position, and keeps the rest of its
+    ///   journey in its route, so that it carries on next turn (see `advance_routes`).
+    /// - `DigIn` resets the unit's destination to its current position, and clears its route.
+    /// - `Sprint` and None route the unit to where it was going again, with its new range (see
+    ///   `set_destination`).
+    ///
+    /// Stances are cleared at the end of every turn.
+    ///
+    /// Returns `()`, or `ArtilleryError` on failure, in which case the unit keeps its previous
+    /// stance and orders. Potential variants:
+    /// - IndexError -> The unit does not exist.
+    /// - TerrainError -> The unit's route is no longer reachable.
+    pub fn set_stance(&mut self, index:usize, stance:Option<Stance>) -> Result<(), ArtilleryError> {
+        let position = match self.get_unit(index) {
+            Err(_) => return Err(ArtilleryError::index_error("set_stance", index)),
+            Ok(position) => position.clone(),
+        };
+        let previous_stance = self.get_stances()[index];
+        let previous_route = self.get_routes()[index].clone();
+        let destination = std::mem::replace(&mut self.get_destinations()[index], position.clone());
+        let goal = previous_route.last().cloned().unwrap_or(destination.clone());
+        self.get_stances()[index] = stance;
+        match stance {
+            Some(Stance::Hold) => {
+                if destination.distance(&position) > 0.0 {
+                    self.get_routes()[index].insert(0, destination);
+                }
+            }
+            Some(Stance::DigIn) => self.get_routes()[index].clear(),
+            Some(Stance::Sprint) | None => {
+                self.get_routes()[index].clear();
+                if goal.distance(&position) > 0.0 {
+                    if let Err(error) = self.set_destination(index, goal.x, goal.y) {
+                        // Restore the unit's orders, so a failed stance changes nothing
+                        self.get_stances()[index] = previous_stance;
+                        self.get_destinations()[index] = destination;
+                        self.get_routes()[index] = previous_route;
+                        return Err(error);
+                    }
+                }
+            }
+        }
         Ok(())
     }
 
+    /// `set_raid` accepts an `index`, and orders that unit to raid the artillery this turn.
+    ///
+    /// If a raiding soldier ends the turn within `counter_battery.range` of a battery, that battery
+    /// is disabled for the next turn, and receives no `max_resources`. Spotters within range are
+    /// disabled too, and reveal nothing. Raiding decoys can't disable anything, but the order
+    /// keeps up the act. The order lasts until the end of the turn (see `run_raids`).
+    ///
+    /// Returns an `IndexError` if the unit does not exist.
+    pub fn set_raid(&mut self, index:usize) -> Result<(), ArtilleryError> {
+        match self.get_raiding().get_mut(index) {
+            None => Err(ArtilleryError::index_error("set_raid", index)),
+            Some(raiding) => {
+                *raiding = true;
+                Ok(())
+            }
+        }
+    }
+
     /// `set_position` accepts an `index`, `x`, and `y` value, and updates the corresponding position
     /// contained in `self.units`.
     ///
@@ -386,14 +1957,19 @@ impl Game {
     /// pointer to any vector which contains coordinates.
     pub fn set_position(&mut self, index:usize, x:f32, y:f32) -> Result<(), ArtilleryError> {
         // Check if unit exists; return early if false
-        if let Err(_) = self.get_unit(index) {
+        if self.get_unit(index).is_err() {
             return Err(ArtilleryError::index_error("set_position", index));
         };
 
         // Check if Coordinate falls outside of map; return early if true
-        let temp_coord = Coordinate {x:x, y:y};
-        if temp_coord.distance(self.get_base_coords()) > self.get_map_radius() {
-            return Err(ArtilleryError::maximum_distance_error("set_destination", "set a unit's destination outside of the map", &temp_coord, &self.base_coords));
+        let temp_coord = Coordinate {x, y};
+        if !self.is_in_map(&temp_coord) {
+            return Err(ArtilleryError::maximum_distance_error("set_position", "set a unit's position outside of the map", &temp_coord, &self.base_coords));
+        }
+
+        // Check if Coordinate falls inside impassable terrain; return early if true
+        if !self.is_passable(&temp_coord) {
+            return Err(ArtilleryError::terrain_error("set_position", "set a unit's position", &temp_coord));
         }
 
         // Checks complete
@@ -401,6 +1977,236 @@ impl Game {
         Ok(())
     }
 
+    /// `configure` accepts a `GameConfig`, and applies every setting that it contains. Settings
+    /// missing from `config` are left unchanged.
+    ///
+    /// All settings are validated before any are applied, so a failed call changes nothing.
+    ///
+    /// Setting `batteries` clears every placed target and ordered spotter, and every battery's
+    /// bank, since their costs depend on the batteries' positions. Setting `base_coords` without
+    /// `batteries` moves battery 0 along with the base.
+    ///
+    /// Returns `()`, or `ArtilleryError` on failure. Potential variants:
+    /// - ConfigError -> A setting was given an invalid value.
+    pub fn configure(&mut self, config: GameConfig) -> Result<(), ArtilleryError> {
+        // Validate every provided setting:
+        if let Some(schedule) = &config.reinforcements {
+            if schedule.turn_interval == 0 {
+                return Err(ArtilleryError::config_error("configure", "`reinforcements.turn_interval` must be at least 1"));
+            }
+        }
+        if let Some(economy) = &config.economy {
+            if ![economy.bank_rate, economy.bank_cap, economy.kill_bonus].iter().all(|value| value.is_finite()) {
+                return Err(ArtilleryError::config_error("configure", "`economy.bank_rate`, `economy.bank_cap` and `economy.kill_bonus` must be finite"));
+            }
+            if !(0.0..=1.0).contains(&economy.bank_rate) {
+                return Err(ArtilleryError::config_error("configure", "`economy.bank_rate` must be between 0.0 and 1.0"));
+            }
+            if economy.bank_cap < 0.0 || economy.kill_bonus < 0.0 {
+                return Err(ArtilleryError::config_error("configure", "`economy.bank_cap` and `economy.kill_bonus` must not be negative"));
+            }
+        }
+        if [config.dispersion, config.max_wind].iter().flatten().any(|value| !value.is_finite()) {
+            return Err(ArtilleryError::config_error("configure", "`dispersion` and `max_wind` must be finite"));
+        }
+        if config.dispersion.is_some_and(|dispersion| !(0.0..=GameConfig::MAX_DISPERSION).contains(&dispersion)) {
+            return Err(ArtilleryError::config_error("configure", &format!("`dispersion` must be between 0.0 and {}", GameConfig::MAX_DISPERSION)));
+        }
+        if config.max_wind.is_some_and(|max_wind| !(0.0..=GameConfig::MAX_WIND).contains(&max_wind)) {
+            return Err(ArtilleryError::config_error("configure", &format!("`max_wind` must be between 0.0 and {}", GameConfig::MAX_WIND)));
+        }
+        if config.flight_time.is_some_and(|flight_time| !(0.0..=GameConfig::MAX_FLIGHT_TIME).contains(&flight_time)) {
+            return Err(ArtilleryError::config_error("configure", &format!("`flight_time` must be between 0.0 and {}", GameConfig::MAX_FLIGHT_TIME)));
+        }
+        if config.counter_battery.as_ref().is_some_and(|counter_battery| counter_battery.range <= 0.0) {
+            return Err(ArtilleryError::config_error("configure", "`counter_battery.range` must be positive"));
+        }
+        if let Some(stance_effects) = &config.stance_effects {
+            if !(0.0..=1.0).contains(&stance_effects.dig_in_radius) {
+                return Err(ArtilleryError::config_error("configure", "`stance_effects.dig_in_radius` must be between 0.0 and 1.0"));
+            }
+            if stance_effects.sprint_range < 1.0 || stance_effects.sprint_vulnerability < 1.0 {
+                return Err(ArtilleryError::config_error("configure", "`stance_effects.sprint_range` and `stance_effects.sprint_vulnerability` must be at least 1.0"));
+            }
+        }
+        if config.unit_footprint.is_some_and(|unit_footprint| unit_footprint < 0.0) {
+            return Err(ArtilleryError::config_error("configure", "`unit_footprint` must not be negative"));
+        }
+        if config.spotting_radius.flatten().is_some_and(|spotting_radius| spotting_radius < 0.0) {
+            return Err(ArtilleryError::config_error("configure", "`spotting_radius` must not be negative"));
+        }
+        if let Some(recon) = &config.recon {
+            if recon.spotter_cost < 0.0 || recon.spotter_radius < 0.0 {
+                return Err(ArtilleryError::config_error("configure", "`recon.spotter_cost` and `recon.spotter_radius` must not be negative"));
+            }
+        }
+        if let Some(objectives) = &config.objectives {
+            if objectives.points.iter().any(|objective| !self.is_in_map(&objective.center)) {
+                return Err(ArtilleryError::config_error("configure", "every objective must lie inside the map"));
+            }
+            if objectives.points.iter().any(|objective| objective.radius <= 0.0 || objective.value < 0.0) {
+                return Err(ArtilleryError::config_error("configure", "objectives need a positive `radius`, and a `value` of at least 0.0"));
+            }
+            if objectives.hold_ticks == Some(0) || objectives.hold_turns == Some(0) {
+                return Err(ArtilleryError::config_error("configure", "`hold_ticks` and `hold_turns` must be positive"));
+            }
+            if objectives.score_to_win.is_some_and(|score| score <= 0.0) {
+                return Err(ArtilleryError::config_error("configure", "`score_to_win` must be positive"));
+            }
+        }
+        if let Some(suppression) = &config.suppression {
+            if suppression.radius_multiplier < 1.0 {
+                return Err(ArtilleryError::config_error("configure", "`suppression.radius_multiplier` must be at least 1.0"));
+            }
+            if !(0.0..=1.0).contains(&suppression.speed_multiplier) {
+                return Err(ArtilleryError::config_error("configure", "`suppression.speed_multiplier` must be between 0.0 and 1.0"));
+            }
+        }
+        if let Some(siege) = &config.siege {
+            if siege.base_health <= 0.0 || siege.damage_per_unit <= 0.0 {
+                return Err(ArtilleryError::config_error("configure", "`siege.base_health` and `siege.damage_per_unit` must be positive"));
+            }
+        }
+        if let Some(defenses) = &config.defenses {
+            if !defenses.iter().flat_map(|turret| &turret.position).all(|position| self.is_in_map(position)) {
+                return Err(ArtilleryError::config_error("configure", "every turret in `defenses` must lie inside the map"));
+            }
+            if defenses.iter().any(|turret| turret.range <= 0.0 || turret.fire_interval == 0 || turret.damage <= 0.0) {
+                return Err(ArtilleryError::config_error("configure", "every turret's `range`, `fire_interval` and `damage` must be positive"));
+            }
+        }
+        if let Some(victory) = &config.victory {
+            if victory.max_turns == Some(0) || victory.army_score.is_some_and(|score| score <= 0.0) {
+                return Err(ArtilleryError::config_error("configure", "`max_turns` and `army_score` must be positive"));
+            }
+            if victory.attrition.is_some_and(|attrition| attrition <= 0.0 || attrition > 1.0) {
+                return Err(ArtilleryError::config_error("configure", "`attrition` must be above 0.0, and at most 1.0"));
+            }
+        }
+        if let Some(terrain) = &config.terrain {
+            let map = Map { terrain: terrain.clone(), ..self.get_map().clone() };
+            if let Err(reason) = map.validate() {
+                return Err(ArtilleryError::config_error("configure", &reason));
+            }
+        }
+        if let Some(elevation) = &config.elevation {
+            let map = Map { elevation: Some(elevation.clone()), ..self.get_map().clone() };
+            if let Err(reason) = map.validate() {
+                return Err(ArtilleryError::config_error("configure", &reason));
+            }
+        }
+        if let Some(base_coords) = &config.base_coords {
+            if !self.is_in_map(base_coords) {
+                return Err(ArtilleryError::config_error("configure", "`base_coords` must lie inside the map"));
+            }
+        }
+        if let Some(batteries) = &config.batteries {
+            if batteries.is_empty() || !batteries.iter().all(|battery| self.is_in_map(battery)) {
+                return Err(ArtilleryError::config_error("configure", "`batteries` must contain at least one position, and all must lie inside the map"));
+            }
+            if config.base_coords.as_ref().is_some_and(|base_coords| base_coords.distance(&batteries[0]) > 0.0) {
+                return Err(ArtilleryError::config_error("configure", "battery 0 is at the base, so `batteries` must start at `base_coords`"));
+            }
+        }
+        if let Some(spawn_region) = &config.spawn_region {
+            if spawn_region.inner_radius < 0.0 || spawn_region.inner_radius > spawn_region.outer_radius {
+                return Err(ArtilleryError::config_error("configure", "`spawn_region` needs 0.0 <= `inner_radius` <= `outer_radius`"));
+            }
+        }
+
+        // Checks complete
+        if let Some(schedule) = config.reinforcements {
+            self.reinforcements = Some(schedule);
+        }
+        if let Some(economy) = config.economy {
+            self.economy = economy;
+        }
+        if let Some(seed) = config.seed {
+            self.seed = seed;
+            self.rng = Rng::new(seed);
+        }
+        if let Some(dispersion) = config.dispersion {
+            self.dispersion = dispersion;
+        }
+        if let Some(max_wind) = config.max_wind {
+            self.max_wind = max_wind;
+        }
+        if let Some(flight_time) = config.flight_time {
+            self.flight_time = flight_time;
+        }
+        if let Some(spotting_radius) = config.spotting_radius {
+            // `null` or 0.0 turns fog of war off
+            self.spotting_radius = spotting_radius.filter(|spotting_radius| *spotting_radius > 0.0);
+        }
+        if let Some(unit_footprint) = config.unit_footprint {
+            self.unit_footprint = unit_footprint;
+        }
+        if let Some(recon) = config.recon {
+            self.recon = recon;
+        }
+        if let Some(counter_battery) = config.counter_battery {
+            self.counter_battery = counter_battery;
+        }
+        if let Some(stance_effects) = config.stance_effects {
+            self.stance_effects = stance_effects;
+        }
+        // `objectives.score_to_win` is a deprecated alias, used unless `victory.army_score` is set too
+        let score_to_win = config.objectives.as_ref()
+            .and_then(|objectives| objectives.score_to_win)
+            .filter(|_| config.victory.as_ref().is_none_or(|victory| victory.army_score.is_none()));
+        if let Some(objectives) = config.objectives {
+            let objectives = Objectives { score_to_win: None, ..objectives };
+            self.objective_ticks = vec![0; objectives.points.len()];
+            self.objective_turns = vec![0; objectives.points.len()];
+            self.army_score = 0.0;
+            self.objectives = objectives;
+        }
+        if let Some(victory) = config.victory {
+            self.victory = victory;
+        }
+        if let Some(score) = score_to_win {
+            self.victory.army_score = Some(score);
+        }
+        if let Some(defenses) = config.defenses {
+            self.defenses = defenses;
+        }
+        if let Some(siege) = config.siege {
+            self.base_health = Some(siege.base_health);
+            self.siege = Some(siege);
+        }
+        if let Some(suppression) = config.suppression {
+            self.suppression = Some(suppression);
+        }
+        if let Some(terrain) = config.terrain {
+            self.map.terrain = terrain;
+        }
+        if let Some(elevation) = config.elevation {
+            self.map.elevation = Some(elevation);
+        }
+        if let Some(base_coords) = config.base_coords {
+            // Battery 0 moves with the base, so its targets are no longer valid
+            if config.batteries.is_none() && base_coords.distance(&self.batteries[0]) > 0.0 {
+                self.batteries[0] = base_coords.clone();
+                self.reset_targets();
+            }
+            self.base_coords = base_coords;
+        }
+        if let Some(batteries) = config.batteries {
+            self.banked_resources = vec![0.0; batteries.len()];
+            self.disabled_batteries = vec![false; batteries.len()];
+            self.batteries = batteries;
+            self.reset_targets();
+            self.get_spotter_orders().clear();
+        }
+        if let Some(spawn_region) = config.spawn_region {
+            self.spawn_region = spawn_region;
+        }
+        if config.seed.is_some() || config.max_wind.is_some() {
+            self.wind = self.draw_wind();
+        }
+        Ok(())
+    }
+
     /// `set_game_over` accepts a boolean value, and sets `self.game_over` to that value.
     /// 
     /// Currently has no use, but may be useful if we wanted to include side effects.
@@ -423,50 +2229,526 @@ impl Game {
          (x_velocity, y_velocity)
     }
 
-    /// `is_in_danger` accepts an index for a target (`target_index`) and an index for a unit
-    /// (`unit_index`) and returns `true` if the unit is within the danger zone.
-    fn is_in_danger(&self, target_index:usize, unit_index:usize) -> bool {
-        let target_coords = &self.targets[target_index];
+    /// `is_in_danger` accepts a `Detonation` and an index for a unit (`unit_index`) and returns
+    /// `true` if the unit is within the danger zone.
+    ///
+    /// Blasts have a smaller radius against dug-in units (see `StanceEffects`).
+    fn is_in_danger(&self, detonation: &Detonation, unit_index:usize) -> bool {
+        let radius = match self.stances[unit_index] {
+            Some(Stance::DigIn) => detonation.radius * self.stance_effects.dig_in_radius,
+            _ => detonation.radius,
+        };
+        self.blast_distance(detonation, unit_index) <= radius
+    }
+
+    /// `blast_distance` accepts a `Detonation` and an index for a unit (`unit_index`) and returns
+    /// the distance between the unit and the blast: from its center, or from the nearest point on
+    /// the line for line blasts.
+    fn blast_distance(&self, detonation: &Detonation, unit_index:usize) -> f32 {
+        let unit_coords = &self.units[unit_index];
+        match &detonation.end {
+            None => detonation.center.distance(unit_coords),
+            Some(end) => unit_coords.distance_to_segment(&detonation.center, end),
+        }
+    }
+
+    /// `vulnerability` accepts the index of a unit, and returns the multiplier applied to all
+    /// damage it takes: `stance_effects.sprint_vulnerability` if it sprinted last turn, or 1.0.
+    fn vulnerability(&self, unit_index:usize) -> f32 {
+        if self.fatigued[unit_index] { self.stance_effects.sprint_vulnerability } else { 1.0 }
+    }
+
+    /// `unit_range` accepts the index of a unit, and returns how far it can move this turn:
+    /// `max_unit_range`, multiplied by `stance_effects.sprint_range` if it is sprinting.
+    fn unit_range(&self, unit_index:usize) -> f32 {
+        match self.stances[unit_index] {
+            Some(Stance::Sprint) => self.max_unit_range * self.stance_effects.sprint_range,
+            _ => self.max_unit_range,
+        }
+    }
+
+    /// `blast_damage` accepts a `Detonation` and an index for a unit (`unit_index`) and returns
+    /// the damage dealt to that unit.
+    ///
+    /// Units outside the danger zone take no damage. Units inside cover take the detonation's
+    /// damage, reduced by the best `protection` of the terrain they are standing in, or of any
+    /// ridge between them and the blast (see `Heightmap::ridge_protection`). Units that sprinted
+    /// last turn take extra damage (see `vulnerability`).
+    fn blast_damage(&self, detonation: &Detonation, unit_index:usize) -> f32 {
+        if !self.is_in_danger(detonation, unit_index) {
+            return 0.0;
+        }
         let unit_coords = &self.units[unit_index];
-        target_coords.contains(unit_coords, self.get_target_radius())
+        // Line blasts strike from the nearest point on the line
+        let origin = match &detonation.end {
+            None => detonation.center.clone(),
+            Some(end) => unit_coords.closest_point_on_segment(&detonation.center, end),
+        };
+        let ridge_protection = self.get_map().elevation
+            .as_ref()
+            .map_or(0.0, |elevation| elevation.ridge_protection(&origin, unit_coords));
+        let protection = self.get_terrain()
+            .iter()
+            .filter(|feature| feature.shape.contains(unit_coords))
+            .map(|feature| feature.protection())
+            .fold(ridge_protection, f32::max);
+        detonation.damage * (1.0 - protection) * self.vulnerability(unit_index)
     }
 
-    /// `shot_cost` accepts a `Coordinate`, and returns the *resource cost* for that shot.
+    /// `shot_cost` accepts a `Coordinate`, the `shape` of the target there, and the index of the
+    /// `battery` firing at it, and returns the *resource cost* for that shot.
+    ///
+    /// This function does not validate that the shot lies within the map, or that the battery
+    /// exists.
     ///
-    /// This function does not validate that the shot lies within the map.
+    /// If the battery has no previous shot, then the distance is calculated from the battery.
+    /// If it has a previous shot, then the distance is calculated from the end of that shot (see
+    /// `TargetShape::end`). The length of a barrage, times its width in multiples of
+    /// `target_radius`, is added to the distance, so that wider barrages cost more.
+    fn shot_cost(&mut self, coord: &Coordinate, shape: &TargetShape, battery: usize) -> f32 {
+        let previous_shot = self.target_batteries.iter().rposition(|target_battery| *target_battery == battery);
+        let distance = match previous_shot {
+            None => self.get_batteries()[battery].distance(coord),
+            Some(index) => self.target_shapes[index].end(&self.targets[index]).distance(coord),
+        } + shape.length(coord) * shape.width() / self.target_radius;
+        0.00122 * distance.powf(2.0) + 0.16 * distance + 4.83
+    }
+
+    /// `is_spotted` accepts a unit's position, and returns true if the artillery player can see it.
     ///
-    /// If there is no previous shot, then the distance is calculated from the base coords.
-    /// If there is a previous shot, then the distance is calculated from the previous shot.
-    fn shot_cost(&mut self, coord: &Coordinate) -> f32 {
-        let distance;
-        if self.get_targets().is_empty() {
-            distance = self.get_base_coords().distance(coord);
+    /// Units inside a smoke cloud are always hidden. Otherwise, units are seen if they are within
+    /// `spotting_radius` of the base or `recon.spotter_radius` of a spotter, or anywhere if there is
+    /// no fog of war.
+    fn is_spotted(&self, unit: &Coordinate) -> bool {
+        let smoke_radius = self.target_radius * 2.0;
+        if self.smoke_clouds.iter().any(|cloud| cloud.contains(unit, smoke_radius)) {
+            return false;
         }
-        else {
-            distance = self.get_targets().clone()[self.get_targets().len() - 1].distance(coord);
+        if self.spotters.iter().any(|spotter| spotter.contains(unit, self.recon.spotter_radius)) {
+            return true;
+        }
+        self.spotting_radius.is_none_or(|spotting_radius| self.base_coords.contains(unit, spotting_radius))
+    }
+
+    /// `is_unit_spotted` accepts the `index` of a unit, and returns true if the artillery player
+    /// can see it. Camouflaged units are always hidden; see `is_spotted` for everything else.
+    fn is_unit_spotted(&self, index: usize) -> bool {
+        !self.camouflaged[index] && self.is_spotted(&self.units[index])
+    }
+
+    /// `turret_target` accepts a `Turret`, and returns the index of the unit it fires at: the
+    /// nearest unit within its range that is still standing, not hidden by camouflage or smoke, and
+    /// not behind impassable terrain.
+    ///
+    /// Returns None if the turret has nothing to fire at.
+    fn turret_target(&self, turret: &Turret) -> Option<usize> {
+        let smoke_radius = self.target_radius * 2.0;
+        let position = turret.position_or(&self.base_coords);
+        (0..self.units.len())
+            .filter(|index| self.health[*index] > 0.0 && !self.camouflaged[*index])
+            .filter(|index| position.contains(&self.units[*index], turret.range))
+            .filter(|index| !self.smoke_clouds.iter().any(|cloud| cloud.contains(&self.units[*index], smoke_radius)))
+            .filter(|index| self.map.is_path_clear(position, &self.units[*index]))
+            .min_by(|a, b| position.distance(&self.units[*a]).total_cmp(&position.distance(&self.units[*b])))
+    }
+
+    /// `update_sightings` records the position of every unit the artillery player can see, and
+    /// ages the sightings of every unit it can't.
+    fn update_sightings(&mut self) {
+        for index in 0..self.units.len() {
+            if self.is_unit_spotted(index) {
+                self.sightings[index] = Some(Sighting { position: self.units[index].clone(), age: 0 });
+            }
+            else if let Some(sighting) = &mut self.sightings[index] {
+                sighting.age += 1;
+            }
+        }
+    }
+
+    /// `held_objectives` returns, for every objective, true if at least one soldier is within its
+    /// radius. Decoys can't hold objectives.
+    fn held_objectives(&self) -> Vec<bool> {
+        self.objectives.points
+            .iter()
+            .map(|objective| {
+                self.units
+                    .iter()
+                    .zip(self.unit_kinds.iter())
+                    .any(|(unit, kind)| *kind == UnitKind::Soldier && unit.contains(&objective.center, objective.radius))
+            })
+            .collect()
+    }
+
+    /// `update_objective_ticks` is called once per tick. It extends the hold of every objective
+    /// the army holds, resets the hold on the rest, and adds the points earned this tick to the
+    /// army's score.
+    ///
+    /// Returns true if the army has met the `hold_ticks` rule.
+    fn update_objective_ticks(&mut self) -> bool {
+        let held = self.held_objectives();
+        for (index, is_held) in held.iter().enumerate() {
+            if *is_held {
+                self.objective_ticks[index] += 1;
+                self.army_score += self.objectives.points[index].value / self.turn_time as f32;
+                self.army_score = (self.army_score * 10000.0).round() / 10000.0; // Due to floating point errors
+            }
+            else {
+                self.objective_ticks[index] = 0;
+            }
+        }
+        self.objectives.hold_ticks
+            .is_some_and(|hold_ticks| self.objective_ticks.iter().any(|ticks| *ticks >= hold_ticks))
+    }
+
+    /// `is_attrition_reached` returns true if the artillery has destroyed at least
+    /// `victory.attrition` of all the soldiers the army has fielded. Soldiers consumed by a siege
+    /// were fielded, but weren't destroyed.
+    fn is_attrition_reached(&self) -> bool {
+        let soldiers_alive = self.unit_kinds.iter().filter(|kind| **kind == UnitKind::Soldier).count();
+        let soldiers_fielded = soldiers_alive + self.soldiers_lost + self.soldiers_consumed;
+        self.victory.attrition.is_some_and(|attrition| {
+            soldiers_fielded > 0 && self.soldiers_lost as f32 / soldiers_fielded as f32 >= attrition
+        })
+    }
+
+    /// `update_objective_turns` is called at the end of each turn. It extends the hold of every
+    /// objective the army holds, and resets the hold on the rest.
+    ///
+    /// Returns true if the army has met the `hold_turns` rule.
+    fn update_objective_turns(&mut self) -> bool {
+        let held = self.held_objectives();
+        for (index, is_held) in held.iter().enumerate() {
+            self.objective_turns[index] = if *is_held { self.objective_turns[index] + 1 } else { 0 };
+        }
+        self.objectives.hold_turns
+            .is_some_and(|hold_turns| self.objective_turns.iter().any(|turns| *turns >= hold_turns))
+    }
+
+    /// `advance_routes` gives every unit with a route its destination for the next turn: as far
+    /// along the next leg of the route as its range allows (see `navigation::clip_path`).
+    ///
+    /// If the next leg has been blocked, for example by new terrain, a new route is found to the
+    /// same goal. Units with no way left to their goal stop where they are.
+    fn advance_routes(&mut self) {
+        for index in 0..self.units.len() {
+            let Some(goal) = self.routes[index].last().cloned() else {
+                continue;
+            };
+            let position = self.units[index].clone();
+            let path = if self.map.is_walkable(&position, &self.routes[index][0]) {
+                [vec![position], self.routes[index].clone()].concat()
+            }
+            else if let Some(path) = navigation::find_path(&self.map, &position, &goal) {
+                path
+            }
+            else {
+                self.routes[index].clear();
+                continue;
+            };
+            let (destination, route) = navigation::clip_path(&self.map, &path, self.max_unit_range);
+            self.destinations[index] = destination;
+            self.routes[index] = route;
         }
-        return 0.00122 * distance.powf(2.0) + 0.16 * distance + 4.83;
     }
 
-    /// `is_in_map` accepts a `Coordinate` and determines if that point is within the map.
+    /// `is_in_map` accepts a `Coordinate` and determines if that point is within the map (see
+    /// `Map::contains`).
     ///
     /// Returns true if inside the map, false if outside the map.
     fn is_in_map(&self, coord: &Coordinate) -> bool {
-        self.get_base_coords().contains(coord, self.get_map_radius())
+        self.get_map().contains(coord)
+    }
+
+    /// `is_passable` accepts a `Coordinate` and determines if a unit can stand there (see
+    /// `Map::is_passable`).
+    ///
+    /// Returns false if the point is inside impassable terrain, true otherwise.
+    fn is_passable(&self, coord: &Coordinate) -> bool {
+        self.get_map().is_passable(coord)
+    }
+
+    /// `separate_units` pushes apart every pair of units that are closer than `unit_footprint`, so
+    /// that no two units occupy the same space.
+    ///
+    /// Each unit of a pair moves half of the overlap directly away from the other. Units that are
+    /// holding or dug in stand their ground, and the other unit moves the whole overlap instead.
+    /// Units on the same point are pushed apart along the x axis. A unit isn't pushed outside the
+    /// map, or into or across impassable terrain (see `push_target`); it stands its ground
+    /// instead.
+    ///
+    /// Returns the index of every unit that was pushed, in order.
+    fn separate_units(&mut self) -> Vec<usize> {
+        let footprint = self.unit_footprint;
+        let mut pushed_units = vec![false; self.units.len()];
+        for first in 0..self.units.len() {
+            for second in first + 1..self.units.len() {
+                let (first_position, second_position) = (self.units[first].clone(), self.units[second].clone());
+                let distance = first_position.distance(&second_position);
+                if distance >= footprint {
+                    continue;
+                }
+                let direction = if distance == 0.0 {
+                    Coordinate { x: 1.0, y: 0.0 }
+                }
+                else {
+                    Coordinate { x: (second_position.x - first_position.x) / distance, y: (second_position.y - first_position.y) / distance }
+                };
+                let overlap = footprint - distance;
+                let standing = |index:usize| matches!(self.stances[index], Some(Stance::Hold | Stance::DigIn));
+                let (first_push, second_push) = match (standing(first), standing(second)) {
+                    (true, true) => continue,
+                    (true, false) => (0.0, overlap),
+                    (false, true) => (overlap, 0.0),
+                    (false, false) => (overlap / 2.0, overlap / 2.0),
+                };
+                let first_target = self.push_target(first, &direction, -first_push);
+                let second_target = self.push_target(second, &direction, second_push);
+                // A unit that can't be pushed stands its ground, and the other unit is pushed the
+                // whole way if it can be
+                let (first_target, second_target) = match (first_target, second_target) {
+                    (Some(target), None) if second_push > 0.0 => (self.push_target(first, &direction, -overlap).or(Some(target)), None),
+                    (None, Some(target)) if first_push > 0.0 => (None, self.push_target(second, &direction, overlap).or(Some(target))),
+                    targets => targets,
+                };
+                for (index, target) in [(first, first_target), (second, second_target)] {
+                    if let Some(target) = target {
+                        self.units[index] = target;
+                        pushed_units[index] = true;
+                    }
+                }
+            }
+        }
+        (0..self.units.len()).filter(|index| pushed_units[*index]).collect()
+    }
+
+    /// `push_target` accepts the index of a unit, a `direction`, and how far to `push` the unit
+    /// along it, and returns where the unit would be pushed to.
+    ///
+    /// Returns None if the unit isn't pushed at all, or if the push would take it outside the map,
+    /// or into or across impassable terrain (see `Map::is_walkable`).
+    fn push_target(&self, index:usize, direction:&Coordinate, push:f32) -> Option<Coordinate> {
+        let position = &self.units[index];
+        let pushed = Coordinate { x: position.x + direction.x * push, y: position.y + direction.y * push };
+        (push != 0.0 && self.map.is_walkable(position, &pushed) && self.is_passable(&pushed)).then_some(pushed)
+    }
+
+    /// `resume_course` accepts the index of a unit that has been pushed aside (see
+    /// `separate_units`), and the number of ticks left in the turn, and returns the velocity that
+    /// takes the unit from where it was pushed to its destination by the end of the turn.
+    ///
+    /// If the unit can't walk straight to its destination from there, it stops, and its destination
+    /// is put back at the front of its route so that it is re-routed next turn (see
+    /// `advance_routes`).
+    fn resume_course(&mut self, index:usize, remaining_ticks:usize) -> (f32, f32) {
+        let position = self.units[index].clone();
+        let destination = self.destinations[index].clone();
+        if remaining_ticks == 0 || destination.distance(&position) == 0.0 {
+            return (0.0, 0.0);
+        }
+        if !self.map.is_walkable(&position, &destination) {
+            self.routes[index].insert(0, destination);
+            self.destinations[index] = position;
+            return (0.0, 0.0);
+        }
+        let x_velocity = (destination.x - position.x) / remaining_ticks as f32;
+        let y_velocity = (destination.y - position.y) / remaining_ticks as f32;
+        (x_velocity, y_velocity)
     }
 
-    /// `reset_targets` clears all `Coordinates` within self.targets, and removes all costs within
-    /// self.target_costs.
+    /// `reinforce` accepts a number of units, `units_per_wave`, and places that many units in the
+    /// spawn region using `add_unit`.
+    ///
+    /// Units are spread evenly around the spawn region, halfway between its inner and outer
+    /// radius. Each wave is rotated by the golden angle so that consecutive waves don't
+    /// arrive at the same spots. Positions rejected by `add_unit` are skipped.
+    ///
+    /// Returns the `Coordinate` of every unit that was placed.
+    fn reinforce(&mut self, units_per_wave: usize) -> Vec<Coordinate> {
+        let mut placed = vec![];
+        let spawn_region = self.get_spawn_region().clone();
+        let center = spawn_region.center;
+        let radius = (spawn_region.inner_radius + spawn_region.outer_radius) / 2.0;
+        let rotation = self.get_turn() as f32 * 2.399_963; // Golden angle, in radians
+        for wave_index in 0..units_per_wave {
+            let angle = rotation + wave_index as f32 * std::f32::consts::TAU / units_per_wave as f32;
+            let x = ((center.x + radius * angle.cos()) * 100.0).round() / 100.0;
+            let y = ((center.y + radius * angle.sin()) * 100.0).round() / 100.0;
+            if self.add_unit(x, y, UnitKind::Soldier).is_ok() {
+                placed.push(Coordinate {x, y});
+            }
+        }
+        placed
+    }
+
+    /// `run_raids` disables every battery and spotter within `counter_battery.range` of a raiding
+    /// soldier (see `set_raid`), replacing the previous turn's disabled batteries. Disabled
+    /// spotters are removed, since they would only have lasted until the end of the next turn.
+    ///
+    /// Must be called after `bank_resources`, since this turn's resources depend on the batteries
+    /// disabled by the previous turn's raids.
+    ///
+    /// Returns the indexes of the disabled batteries, and the number of disabled spotters.
+    fn run_raids(&mut self) -> (Vec<usize>, usize) {
+        let range = self.counter_battery.range;
+        let raiders: Vec<Coordinate> = (0..self.units.len())
+            .filter(|index| self.raiding[*index] && self.unit_kinds[*index] == UnitKind::Soldier)
+            .map(|index| self.units[index].clone())
+            .collect();
+        self.disabled_batteries = self.batteries
+            .iter()
+            .map(|battery| raiders.iter().any(|raider| raider.contains(battery, range)))
+            .collect();
+        let spotters_before = self.spotters.len();
+        self.spotters.retain(|spotter| !raiders.iter().any(|raider| raider.contains(spotter, range)));
+        let disabled = (0..self.batteries.len()).filter(|battery| self.disabled_batteries[*battery]).collect();
+        (disabled, spotters_before - self.spotters.len())
+    }
+
+    /// `bank_resources` accepts the number of units destroyed this turn, `units_destroyed`, and
+    /// replaces `self.banked_resources` with the resources each battery carries into the next turn
+    /// according to `self.economy`. Each battery banks its own unspent resources, and the kill
+    /// bonus is shared evenly between the batteries.
+    ///
+    /// Must be called before `reset_targets`, since the unspent resources depend on the current
+    /// target costs.
+    ///
+    /// Returns the new amount of banked resources for each battery.
+    fn bank_resources(&mut self, units_destroyed: usize) -> Vec<f32> {
+        let economy = self.get_economy().clone();
+        let kill_bonus = units_destroyed as f32 * economy.kill_bonus / self.get_batteries().len() as f32;
+        self.banked_resources = self.get_all_available_resources()
+            .iter()
+            .map(|unspent| (unspent.max(0.0) * economy.bank_rate + kill_bonus).min(economy.bank_cap))
+            .collect();
+        self.banked_resources.clone()
+    }
+
+    /// `draw_wind` draws a new wind vector from `self.rng`, no stronger than `max_wind`.
+    ///
+    /// Positions are rounded to 2 decimal places, like unit positions.
+    fn draw_wind(&mut self) -> Coordinate {
+        let max_wind = self.get_max_wind();
+        let wind = self.rng.point_in_circle(max_wind);
+        Coordinate { x: (wind.x * 100.0).round() / 100.0, y: (wind.y * 100.0).round() / 100.0 }
+    }
+
+    /// `impact_point` accepts the `Coordinate` of a target and the index of the `battery` firing at
+    /// it, and returns where the shell actually lands.
+    ///
+    /// The shell is pushed by the current wind, then strays up to `dispersion` units for every
+    /// unit of distance between the target and the battery. The stray is drawn from `self.rng`, so
+    /// the outcome is reproducible from the seed and the order in which targets are resolved.
+    fn impact_point(&mut self, target: &Coordinate, battery: usize) -> Coordinate {
+        let spread = self.get_dispersion() * self.get_batteries()[battery].distance(target);
+        let stray = self.rng.point_in_circle(spread);
+        let wind = self.get_wind().clone();
+        Coordinate { x: target.x + wind.x + stray.x, y: target.y + wind.y + stray.y }
+    }
+
+    /// `detonations` accepts the points where each target's shell lands, `impacts` (see
+    /// `impact_point`), and the timing of each shot, `schedule` (see `get_target_schedule`), and
+    /// expands every target into the blasts it produces. Barrages are expanded by
+    /// `barrage_detonations`, and point targets according to their `Munition`:
+    /// - Standard -> One blast of `target_radius`, dealing 1.0 damage
+    /// - Airburst -> One blast of twice `target_radius`, dealing 0.5 damage
+    /// - Smoke -> One smoke cloud of twice `target_radius`, dealing no damage
+    /// - Cluster -> One blast of half `target_radius` on the target, and `CLUSTER_BOMBLETS` more
+    ///   spaced evenly on a circle of `target_radius` around it, each dealing 1.0 damage
+    /// - DelayedFuse -> One blast of `target_radius`. The fuse delay is part of its `impact_tick`.
+    fn detonations(&mut self, impacts: &[Coordinate], schedule: &[ShotTiming]) -> Vec<Detonation> {
+        let target_radius = self.get_target_radius();
+        let munitions = self.get_target_munitions().clone();
+        let targets = self.get_targets().clone();
+        let shapes = self.get_target_shapes().clone();
+        let mut detonations = vec![];
+        for (index, center) in impacts.iter().cloned().enumerate() {
+            let tick = schedule[index].impact_tick;
+            if !matches!(shapes[index], TargetShape::Point) {
+                // Wind and dispersion move the whole barrage, so its end moves with its start
+                let end = shapes[index].end(&targets[index]);
+                let end = Coordinate { x: end.x + center.x - targets[index].x, y: end.y + center.y - targets[index].y };
+                detonations.extend(self.barrage_detonations(&shapes[index], munitions[index], center, end, tick));
+                continue;
+            }
+            match munitions[index] {
+                Munition::Standard | Munition::DelayedFuse => detonations.push(Detonation { center, end: None, radius: target_radius, damage: 1.0, tick, smoke: false }),
+                Munition::Airburst => detonations.push(Detonation { center, end: None, radius: target_radius * 2.0, damage: 0.5, tick, smoke: false }),
+                Munition::Smoke => detonations.push(Detonation { center, end: None, radius: target_radius * 2.0, damage: 0.0, tick, smoke: true }),
+                Munition::Cluster => {
+                    detonations.push(Detonation { center: center.clone(), end: None, radius: target_radius / 2.0, damage: 1.0, tick, smoke: false });
+                    for bomblet in 0..Munition::CLUSTER_BOMBLETS {
+                        let angle = bomblet as f32 * std::f32::consts::TAU / Munition::CLUSTER_BOMBLETS as f32;
+                        let bomblet_center = Coordinate {
+                            x: center.x + target_radius * angle.cos(),
+                            y: center.y + target_radius * angle.sin(),
+                        };
+                        detonations.push(Detonation { center: bomblet_center, end: None, radius: target_radius / 2.0, damage: 1.0, tick, smoke: false });
+                    }
+                },
+            }
+        }
+        detonations
+    }
+
+    /// `barrage_detonations` accepts the `shape` and `munition` of a barrage, where it lands
+    /// (`start` and `end`), and the `tick` it lands on, and returns the blasts it produces:
+    /// - Line -> One blast covering the line, `width` wide, dealing the munition's
+    ///   `barrage_damage`
+    /// - Creeping -> Blasts of `width` spaced (at most) `width` apart along the line, dealing the
+    ///   munition's `barrage_damage`. The first lands on `tick`, and the rest are spread evenly
+    ///   over the following `duration` ticks, ending no later than the final tick of the turn.
+    ///
+    /// Smoke barrages lay smoke clouds instead, spaced at most `target_radius` apart along a line,
+    /// or one at each step of a creeping barrage.
+    fn barrage_detonations(&self, shape: &TargetShape, munition: Munition, start: Coordinate, end: Coordinate, tick: usize) -> Vec<Detonation> {
+        let smoke = munition == Munition::Smoke;
+        let damage = munition.barrage_damage();
+        let smoke_radius = self.target_radius * 2.0;
+        match *shape {
+            TargetShape::Point => vec![],
+            TargetShape::Line { .. } if smoke => {
+                let clouds = (start.distance(&end) / self.target_radius).ceil().max(1.0) as usize;
+                (0..=clouds)
+                    .map(|cloud| Detonation { center: start.along(&end, cloud as f32 / clouds as f32), end: None, radius: smoke_radius, damage, tick, smoke })
+                    .collect()
+            }
+            TargetShape::Line { width, .. } => vec![Detonation { center: start, end: Some(end), radius: width / 2.0, damage, tick, smoke }],
+            TargetShape::Creeping { width, duration, .. } => {
+                let last_tick = self.turn_time.saturating_sub(1);
+                let steps = (start.distance(&end) / width).ceil().max(1.0) as usize;
+                let radius = if smoke { smoke_radius } else { width / 2.0 };
+                (0..=steps)
+                    .map(|step| Detonation {
+                        center: start.along(&end, step as f32 / steps as f32),
+                        end: None,
+                        radius,
+                        damage,
+                        tick: (tick + step * duration / steps).min(last_tick),
+                        smoke,
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// `reset_targets` clears all `Coordinates` within self.targets, and removes all costs,
+    /// munitions, batteries and shapes within self.target_costs, self.target_munitions,
+    /// self.target_batteries and self.target_shapes.
     /// 
     /// Should never fail.
     pub fn reset_targets(&mut self) {
         self.get_targets().clear();
         self.get_target_costs().clear();
+        self.get_target_munitions().clear();
+        self.get_target_batteries().clear();
+        self.get_target_shapes().clear();
     }
 
-    /// `reset_game` replaces itself with a fresh copy of the game.
+    /// `reset_game` replaces itself with a fresh copy of the game, played on the same map. The
+    /// base, spawn region and batteries are set up from the map again (see `with_map`).
     pub fn reset_game(&mut self) {
-        *self = Game::new();
+        let map = self.get_map().clone();
+        *self = Game::with_map(map).expect("The map MUST have been validated when the game was set up.");
     }
 // helpers END
 //
@@ -480,31 +2762,61 @@ impl Game {
     ///
     /// `run_turn` performs the following tasks:
     /// 1. Calculate the velocities of all units
-    /// 2. Calculate the timing of artillery fire.
+    /// 2. Calculate the timing of artillery fire (see `get_target_schedule`).
     ///     - Each shot is represented by an integer 'm' within an iterable. The main loop iterates
-    ///     'n' times, where n = `self.turn_time`. When `n == m`, an explosion occurs and units are
-    ///     checked for danger.
+    ///       'n' times, where n = `self.turn_time`. When `n == m`, an explosion occurs and units are
+    ///       checked for danger.
+    ///     - Each target is expanded into its blasts according to its munition, and moved by wind and
+    ///       dispersion (see `detonations`).
     /// 3. Iterate over each 'tick' set by `self.turn_time`. Each iteration:
-    ///     1. Add velocity to each unit's coordinates to determine new position
-    ///     2. If an explosion happens that tick, determine units in danger zones. If effected, the
-    ///        unit is removed from the game using `remove_unit`
-    /// 4. Determine if either player has won the game.
+    ///     1. Add velocity to each unit's coordinates to determine new position, and push apart
+    ///        units that overlap (see `separate_units`). Pushed units head for their destination
+    ///        again from where they were pushed to (see `resume_course`). Units that move over a
+    ///        spotter destroy it.
+    ///     2. If an explosion happens that tick, determine units in danger zones. Effected units
+    ///        take damage (reduced by cover, see `blast_damage`), units near the blast are slowed
+    ///        down (see `Suppression`), and units with no health left
+    ///        are removed using `remove_unit`. Smoke
+    ///        clouds are kept until the end of the next turn (see `get_visible_units`). Turrets
+    ///        that are ready fire at the nearest unit in range (see `Turret`).
+    ///     3. If the base can withstand a siege, soldiers that have reached it damage it once, and
+    ///        are consumed (see `Siege`).
+    /// 4. Determine if either player has won the game. Decoys don't count towards either win. The
+    ///    army can also win by holding objectives (see `Objectives`), and the game can end on
+    ///    attrition or a turn limit (see `VictoryCondition`). The reason is reported as a
+    ///    `WinReason`.
+    /// 5. If nobody has won, move units with a route on to its next leg (see `advance_routes`), bank
+    ///    unspent resources (see `bank_resources`), place any
+    ///    reinforcements that are due (see `reinforce`), place ordered spotters (see
+    ///    `add_spotter`), disable batteries and spotters near raiding soldiers (see `run_raids`),
+    ///    record what the artillery player can see
+    ///    (see `update_sightings`), tire out units that sprinted and clear every stance (see
+    ///    `Stance`), and draw the wind for the next turn.
+    ///
+    /// The outcome of the turn is stored as a `TurnReport`, and can be retrieved with
+    /// `get_last_report`.
     ///
     /// Returns 0 with no winners, 1 if the army player wins, 2 if the artillery player wins.
     pub fn run_turn(&mut self) -> Result<usize, ArtilleryError> {
+        let mut report = TurnReport { turn: self.get_turn() + 1, ..TurnReport::default() };
+
         // Calculate velocities:
         let mut velocities = vec![];
         for index in 0..self.get_units().len() {
             velocities.push(self.calculate_velocity(index));
         }
 
-        let mut target_index = 0; // First target index
+        let mut suppressed = vec![false; self.get_units().len()]; // Units pinned down this turn
         let mut destroyed_units_index = vec![]; // List of destroyed units by index
-        let target_costs = self.get_target_costs() // List of target costs
-            .clone()
-            .into_iter()
-            .map(|target_cost_as_float| target_cost_as_float.floor() as usize)
-            .collect::<Vec<_>>();
+        report.wind = self.get_wind().clone();
+        report.impacts = self.get_targets().clone() // Where each shell actually lands
+            .iter()
+            .zip(self.get_target_batteries().clone())
+            .map(|(target, battery)| self.impact_point(target, battery))
+            .collect();
+        report.schedule = self.get_target_schedule(); // When each shell is fired, and when it lands
+        let detonations = self.detonations(&report.impacts, &report.schedule); // Every blast, and the tick it occurs on
+        self.get_smoke_clouds().clear(); // Smoke from the previous turn has cleared
         // Iterate n = self.turn_time times to simulate a turn
         for cur_tick in 0..self.turn_time {
             // Add velocity components 
@@ -512,12 +2824,28 @@ impl Game {
                 self.get_units()[index].x += velocity.0;
                 self.get_units()[index].y += velocity.1;
             }
+            // Units can't occupy the same space. Pushed units set off for their destination again
+            // from where they were pushed to, at the same pace if they have been suppressed
+            let remaining_ticks = self.turn_time - cur_tick - 1;
+            for index in self.separate_units() {
+                velocities[index] = self.resume_course(index, remaining_ticks);
+                if let (true, Some(suppression)) = (suppressed[index], &self.suppression) {
+                    velocities[index].0 *= suppression.speed_multiplier;
+                    velocities[index].1 *= suppression.speed_multiplier;
+                }
+            }
+
+            // Units moving over a spotter destroy it
+            let units = self.get_units().clone();
+            let spotters_before = self.get_spotters().len();
+            self.get_spotters().retain(|spotter| !units.iter().any(|unit| unit.contains(spotter, Recon::SPOTTER_FOOTPRINT)));
+            report.spotters_destroyed += spotters_before - self.get_spotters().len();
 
             // Check if an explosion occurs; mark units in danger
             // Each entry in target_costs is represented by an f32. This float represents the
-            // resource cost for each shot. These must be rounded-down and cast as integers.
-            // They must be integers because the game iterates over a range of integers, and we can
-            // determine the timing of the shots by matching the two numbers.
+            // resource cost for each shot. These are rounded-down and cast as integers by
+            // `get_target_schedule`. They must be integers because the game iterates over a range of
+            // integers, and we can determine the timing of the shots by matching the two numbers.
             // Example:
             // targets = [(10, 20), (30, 40), (50, 60)] ==> The coordinates of each target
             // target_costs = [30, 5, 10, ...] ==> The individual cost of each shot/target
@@ -530,42 +2858,111 @@ impl Game {
             //  IF a unit is caught, remove them
             // WHEN n = target_costs[2] + target_costs[1] + target_costs[0] == 30+5+10 = 45
             //  .... AND SO ON
-            if self.get_targets().len() != 0 { // Check to prevent panic if there are no targets
-                if target_costs[0..target_index].into_iter().sum::<usize>() == cur_tick {
+            for detonation in detonations.iter().filter(|detonation| detonation.tick == cur_tick) {
+                if detonation.smoke {
+                    self.get_smoke_clouds().push(detonation.center.clone());
+                    continue;
+                }
+                for unit_index in 0..self.get_units().len() {
+                    let damage = self.blast_damage(detonation, unit_index);
+                    self.get_health()[unit_index] -= damage;
+                }
+                // Units near the blast are slowed down for the rest of the turn (see `Suppression`)
+                if let Some(suppression) = self.suppression.clone() {
                     for unit_index in 0..self.get_units().len() {
-                        if self.is_in_danger(target_index, unit_index) {
-                            destroyed_units_index.push(unit_index); 
+                        let suppression_radius = detonation.radius * suppression.radius_multiplier;
+                        if !suppressed[unit_index] && self.blast_distance(detonation, unit_index) <= suppression_radius {
+                            suppressed[unit_index] = true;
+                            velocities[unit_index].0 *= suppression.speed_multiplier;
+                            velocities[unit_index].1 *= suppression.speed_multiplier;
+                            report.units_suppressed += 1;
                         }
                     }
-                    if target_index < self.get_targets().len() - 1 {
-                        target_index += 1; // After all units are checked, move up the target
-                    }
+                }
+            }
+            // Turrets fire at the nearest unit in range, when they're ready (see `Turret`)
+            for (turret_index, turret) in self.defenses.clone().iter().enumerate() {
+                if !cur_tick.is_multiple_of(turret.fire_interval) {
+                    continue;
+                }
+                if let Some(unit_index) = self.turret_target(turret) {
+                    self.get_health()[unit_index] -= turret.damage * self.vulnerability(unit_index);
+                    report.turret_shots.push(TurretShot { turret: turret_index, tick: cur_tick, target: self.units[unit_index].clone() });
+                }
+            }
+            for unit_index in 0..self.get_units().len() {
+                if self.get_health()[unit_index] <= 0.0 {
+                    destroyed_units_index.push(unit_index);
                 }
             }
 
             // Remove units in danger. Sorting the vector and then popping the elements 
             // prevents index side-effects caused by removing items from the list.
             destroyed_units_index.sort();
+            report.units_destroyed += destroyed_units_index.len();
             while let Some(index) = destroyed_units_index.pop() {
+                if self.get_unit_kinds()[index] == UnitKind::Soldier {
+                    self.soldiers_lost += 1;
+                }
                 self.remove_unit(index).expect("destroyed_units_index MUST match Game.units at this point.");
                 velocities.remove(index); // Must remove associated velocity for destroyed units
+                suppressed.remove(index);
+            }
+
+            // Soldiers that reach a besieged base damage it once, and are consumed (see `Siege`)
+            if let (Some(siege), Some(base_health)) = (self.siege.clone(), self.base_health) {
+                let base_coords = self.get_base_coords().clone();
+                let base_radius = self.get_base_radius();
+                let mut consumed = 0;
+                for index in (0..self.get_units().len()).rev() {
+                    if self.unit_kinds[index] == UnitKind::Soldier && self.units[index].contains(&base_coords, base_radius) {
+                        self.remove_unit(index).expect("index MUST match Game.units at this point.");
+                        velocities.remove(index);
+                        suppressed.remove(index);
+                        consumed += 1;
+                    }
+                }
+                report.units_consumed += consumed;
+                self.soldiers_consumed += consumed;
+                self.base_health = Some((base_health - consumed as f32 * siege.damage_per_unit).max(0.0));
+            }
+
+            // Check if either player has won. Decoys don't count for either player:
+            // Player 1 wins if a besieged base has no hit points left
+            if self.base_health == Some(0.0) {
+                return Ok(self.finish_turn(report, Some(WinReason::BaseCaptured)));
+            }
+            // Player 2 wins if there are no soldiers on the board
+            if !self.get_unit_kinds().contains(&UnitKind::Soldier) {
+                return Ok(self.finish_turn(report, Some(WinReason::ArmyDestroyed)));
             }
-            
-            // Check if either player has won:
-            // Player 2 wins if there are no units on the board
-            if self.get_units().is_empty() {
-                self.set_game_over(true).expect("`set_game_over` shouldn't fail as of 2025-06-05");
-                return Ok(2);
+            // Player 2 also wins once enough soldiers are destroyed (see `VictoryCondition`)
+            if self.is_attrition_reached() {
+                return Ok(self.finish_turn(report, Some(WinReason::Attrition)));
             }
-            // Player 1 wins if there is a unit at the base
+            // Player 1 wins if there is a soldier at the base. Soldiers never stay inside a
+            // besieged base, so this only applies without a siege
             let base_coords = self.get_base_coords().clone();
-            let base_radius = self.get_base_radius().clone();
-            for unit in self.get_units() { // Player 1 checks
-                if unit.contains(&base_coords, base_radius) {
-                    self.set_game_over(true).expect("`set_game_over` shouldn't fail as of 2025-06-05");
-                    return Ok(1);
+            let base_radius = self.get_base_radius();
+            for (unit, kind) in self.units.iter().zip(self.unit_kinds.iter()) { // Player 1 checks
+                if *kind == UnitKind::Soldier && unit.contains(&base_coords, base_radius) {
+                    return Ok(self.finish_turn(report, Some(WinReason::BaseCaptured)));
                 }
             }
+            // Player 1 also wins by holding objectives (see `Objectives`), or by scoring enough
+            if self.update_objective_ticks() {
+                return Ok(self.finish_turn(report, Some(WinReason::ObjectiveHeld)));
+            }
+            if self.victory.army_score.is_some_and(|army_score| self.army_score >= army_score) {
+                return Ok(self.finish_turn(report, Some(WinReason::ArmyScore)));
+            }
+        }
+        if self.update_objective_turns() {
+            return Ok(self.finish_turn(report, Some(WinReason::ObjectiveHeld)));
+        }
+        // Player 2 wins if the army runs out of turns
+        if self.victory.max_turns.is_some_and(|max_turns| report.turn >= max_turns) {
+            return Ok(self.finish_turn(report, Some(WinReason::TurnLimit)));
         }
         // If neither player has won by now:
         // Round positions to 2 decimal places, due to floating point errors:
@@ -573,10 +2970,55 @@ impl Game {
             self.get_units()[index].x = (self.get_units()[index].x * 100.0).round() / 100.0;
             self.get_units()[index].y = (self.get_units()[index].y * 100.0).round() / 100.0;
         }
-        // Clean up targets:
+        // Move units with a route on to its next leg:
+        self.advance_routes();
+        // Bank unspent resources, then clean up targets:
+        report.banked_resources = self.bank_resources(report.units_destroyed);
         self.reset_targets();
+        // Place the spotters ordered this turn, replacing the previous turn's spotters:
+        self.spotters = std::mem::take(&mut self.spotter_orders);
+        // Raiding soldiers disable nearby batteries and spotters for the next turn:
+        (report.batteries_disabled, report.spotters_disabled) = self.run_raids();
+        self.get_raiding().fill(false);
+        // Reinforce the army, if a wave is due this turn:
+        if let Some(schedule) = self.get_reinforcements().clone() {
+            if report.turn.is_multiple_of(schedule.turn_interval) {
+                report.reinforcements = self.reinforce(schedule.units_per_wave);
+            }
+        }
+        // Update what the artillery player has seen of the army, then lift last turn's camouflage.
+        // Units camouflaged this turn stay hidden until the next turn has been played:
+        self.update_sightings();
+        self.camouflaged = std::mem::take(&mut self.camouflage_orders);
+        self.camouflage_orders = vec![false; self.camouflaged.len()];
+        // Units that sprinted are vulnerable next turn, and every stance is cleared:
+        self.fatigued = self.stances.iter().map(|stance| *stance == Some(Stance::Sprint)).collect();
+        self.get_stances().fill(None);
+        // Draw the wind for the next turn:
+        self.wind = self.draw_wind();
         // Return 0 for no winners
-        return Ok(0);
+        Ok(self.finish_turn(report, None))
+    }
+
+    /// `finish_turn` accepts the `report` for the turn being played and the `reason` the game
+    /// ended that turn, if it did, and records both on the game. If there is a winner, the game
+    /// is marked as over.
+    ///
+    /// Returns the winner (see `WinReason::winner`), or 0 if nobody has won, so that `run_turn`
+    /// can return it directly.
+    fn finish_turn(&mut self, mut report: TurnReport, reason: Option<WinReason>) -> usize {
+        let winner = reason.map_or(0, |reason| reason.winner());
+        report.objectives_held = self.held_objectives();
+        report.army_score = self.army_score;
+        report.base_health = self.base_health;
+        if winner != 0 {
+            self.set_game_over(true).expect("`set_game_over` shouldn't fail as of 2025-06-05");
+        }
+        self.turn = report.turn;
+        report.winner = winner;
+        report.reason = reason;
+        self.last_report = report;
+        winner
     }
 // main LOOP
 }
@@ -2,26 +2,64 @@ use std::sync::Arc;
 use tokio::sync::Mutex;
 
 mod game;
+mod map;
+mod mapgen;
+mod navigation;
+mod rng;
 type Game = Arc<Mutex<game::Game>>;
 
 /// This is the entry point for hosting an Artillery Game server.
 ///
 /// Running this will start a `warp` server on port 10707.
+///
+/// Pass `--map <path>` to play on a map loaded from a JSON file (see `map::Map`). Otherwise, the
+/// default circular map is used.
 /// TODO: ArtilleryErrors are serializable, and can be included in `response` HashMaps
 ///
 /// All paths either return or accept JSON objects.
 /// URI paths:
-/// - /units GET -> returns a list of all units' positions in a list
-/// - /units/:index GET (index=usize) -> returns a single unit's position at `index`
-/// - /units POST -> creates a unit at position `x`, `y`, from a json payload
+/// - /units GET -> returns the positions of the units the artillery player can see, in a list
+/// - /units/:index GET (index=usize) -> returns a single unit's position at `index` in the list of
+///   units the artillery player can see
+/// - /army/units GET -> returns every unit's position, destination and orders, and the army's
+///   losses, for the army player
+/// - /army/units/:index GET (index=usize) -> returns a single unit's position, destination and
+///   orders at `index`, for the army player
+/// - /units/visible GET -> returns the positions of all units the artillery player can see, and
+///   the last known positions of the rest
+/// - /units POST -> creates a unit at position `x`, `y` with an optional `kind`, from a json
+///   payload
+/// - /units/:index POST (index=usize) -> routes the unit at `index` to position `x`, `y`, from a
+///   json payload
+/// - /units/group POST -> routes several `units` to position `x`, `y` in a `formation`, from a
+///   json payload
+/// - /units/:index/camouflage POST (index=usize) -> camouflages the unit at `index` until the
+///   next turn has been played
+/// - /units/:index/raid POST (index=usize) -> orders the unit at `index` to raid nearby batteries
+///   and spotters at the end of the turn
+/// - /units/:index/stance POST (index=usize) -> sets the `stance` of the unit at `index` for the
+///   turn, from a json payload
 /// - /units/:index DELETE (index=usize) -> deletes the unit at `index`
 /// TODO: MISSING POSITION ENDPOINT
 /// - /targets GET -> returns a list of all targets' positions in a list
 /// - /targets:index GET (index=usize) -> returns a single target's position at `index`
-/// - /targets POST -> creates a target at position `x`, `y`, from a json payload
+/// - /targets/schedule GET -> returns the fire tick and impact tick of every target
+/// - /targets POST -> creates a target at position `x`, `y` with an optional `munition`, `shape`
+///   and `battery`, from a json payload
 /// - /targets DELETE -> deletes the newest target
+/// - /spotters GET -> returns the active spotters, and the spotters ordered this turn
+/// - /spotters POST -> orders a spotter at position `x`, `y`, from a json payload
+/// - /spotters DELETE -> cancels the newest spotter order
 /// - /game GET ->  returns the currently defined configuration for the game
+/// - /game/config POST -> applies a partial configuration to the game, from a json payload
+/// - /game/report GET -> returns the report for the most recently played turn, including the
+///   winner and the reason they won
+/// - /game/objectives GET -> returns the objectives, how long each has been held, and the army's
+///   score
+/// - /game/map GET -> returns the map, every terrain feature on it, and its elevation
 /// - /game/run POST -> runs the simulation using `Game.run_turn`
+/// - /maps/generate POST -> generates a random map from a seed and optional settings, from a json
+///   payload
 /// TODO: both players must run the game, so the server needs to track the connections or name in
 /// some way. perhaps all functions could simply accept a name to validate.
 #[tokio::main]
@@ -29,7 +67,25 @@ async fn main() {
     use game::Game;
     use filters;
 
-    let game = Arc::new(Mutex::new(Game::new()));
+    let args: Vec<String> = std::env::args().collect();
+    let game = match args.iter().position(|arg| arg == "--map") {
+        None => Game::new(),
+        Some(flag_index) => {
+            let Some(path) = args.get(flag_index + 1) else {
+                eprintln!("--map expects a path to a JSON map file");
+                std::process::exit(1);
+            };
+            let map = map::Map::load(path).unwrap_or_else(|reason| {
+                eprintln!("Failed to load the map: {reason}");
+                std::process::exit(1);
+            });
+            Game::with_map(map).unwrap_or_else(|error| {
+                eprintln!("{error:?}");
+                std::process::exit(1);
+            })
+        }
+    };
+    let game = Arc::new(Mutex::new(game));
 
     let api = filters::all_filters(game);
     warp::serve(api).run(([127, 0, 0, 1], 10707)).await;
@@ -39,7 +95,8 @@ mod filters {
     use warp::Filter;
     use crate::handlers;
     use crate::Game;
-    use crate::game::Coordinate;
+    use crate::game::{Coordinate, GameConfig, GroupOrder, StanceOrder, TargetOrder, UnitOrder};
+    use crate::mapgen::MapParams;
 
 
     /// All filters combined. 
@@ -49,22 +106,38 @@ mod filters {
     ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
         get_all_units(game.clone())
             .or(get_unit(game.clone()))
+            .or(get_visible_units(game.clone()))
+            .or(get_army_units(game.clone()))
+            .or(get_army_unit(game.clone()))
             .or(create_unit(game.clone()))
             .or(delete_unit(game.clone()))
             .or(set_destination(game.clone()))
+            .or(set_group_destination(game.clone()))
+            .or(camouflage_unit(game.clone()))
+            .or(raid_unit(game.clone()))
+            .or(set_stance(game.clone()))
             .or(get_all_targets(game.clone()))
             .or(get_target(game.clone()))
+            .or(get_target_schedule(game.clone()))
             .or(create_target(game.clone()))
             .or(delete_target(game.clone()))
+            .or(get_spotters(game.clone()))
+            .or(create_spotter(game.clone()))
+            .or(delete_spotter(game.clone()))
             .or(get_game_config(game.clone()))
+            .or(configure_game(game.clone()))
+            .or(get_turn_report(game.clone()))
+            .or(get_objectives(game.clone()))
+            .or(get_map(game.clone()))
             .or(run_turn(game.clone()))
+            .or(generate_map())
     }
 
-    /// *   * **   * ***** ******* ******
-    /// *   * * *  *   *      *    ** 
-    /// *   * *  * *   *      *      **
-    /// *   * *   **   *      *        **
-    /// ***** *    * *****    *    ******
+    // *   * **   * ***** ******* ******
+    // *   * * *  *   *      *    ** 
+    // *   * *  * *   *      *      **
+    // *   * *   **   *      *        **
+    // ***** *    * *****    *    ******
 
     /// GET /units
     pub fn get_all_units(
@@ -86,13 +159,43 @@ mod filters {
             .and_then(handlers::get_unit)
     }
 
+    /// GET /units/visible
+    pub fn get_visible_units(
+        game: Game,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        warp::path!("units" / "visible")
+            .and(warp::get())
+            .and(with_game(game))
+            .and_then(handlers::get_visible_units)
+    }
+
+    /// GET /army/units
+    pub fn get_army_units(
+        game: Game,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        warp::path!("army" / "units")
+            .and(warp::get())
+            .and(with_game(game))
+            .and_then(handlers::get_army_units)
+    }
+
+    /// GET /army/units/:index
+    pub fn get_army_unit(
+        game: Game,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        warp::path!("army" / "units" / usize)
+            .and(warp::get())
+            .and(with_game(game))
+            .and_then(handlers::get_army_unit)
+    }
+
     /// POST /units
     pub fn create_unit(
         game: Game,
     ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
         warp::path!("units")
             .and(warp::post())
-            .and(extract_coordinate_from_json())
+            .and(extract_unit_order_from_json())
             .and(with_game(game))
             .and_then(handlers::create_unit)
     }
@@ -108,6 +211,48 @@ mod filters {
             .and_then(handlers::set_destination)
     }
 
+    /// POST /units/group
+    pub fn set_group_destination(
+        game: Game,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        warp::path!("units" / "group")
+            .and(warp::post())
+            .and(extract_group_order_from_json())
+            .and(with_game(game))
+            .and_then(handlers::set_group_destination)
+    }
+
+    /// POST /units/:index/camouflage
+    pub fn camouflage_unit(
+        game: Game,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        warp::path!("units" / usize / "camouflage")
+            .and(warp::post())
+            .and(with_game(game))
+            .and_then(handlers::camouflage_unit)
+    }
+
+    /// POST /units/:index/raid
+    pub fn raid_unit(
+        game: Game,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        warp::path!("units" / usize / "raid")
+            .and(warp::post())
+            .and(with_game(game))
+            .and_then(handlers::raid_unit)
+    }
+
+    /// POST /units/:index/stance
+    pub fn set_stance(
+        game: Game,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        warp::path!("units" / usize / "stance")
+            .and(warp::post())
+            .and(extract_stance_order_from_json())
+            .and(with_game(game))
+            .and_then(handlers::set_stance)
+    }
+
 
     /// DELETE /units/:index
     pub fn delete_unit(
@@ -119,11 +264,11 @@ mod filters {
             .and_then(handlers::delete_unit)
     }
 
-    /// *******  *     ***** ***** ***** ******* *******
-    ///    *    * *    *   * *     *        *     **
-    ///    *   *****   ****  * *** *****    *       **
-    ///    *  *     *  *  ** *   * *        *         **
-    ///    * *       * *   * ***** *****    *    *******
+    // *******  *     ***** ***** ***** ******* *******
+    //    *    * *    *   * *     *        *     **
+    //    *   *****   ****  * *** *****    *       **
+    //    *  *     *  *  ** *   * *        *         **
+    //    * *       * *   * ***** *****    *    *******
 
     /// GET /targets
     pub fn get_all_targets(
@@ -145,13 +290,23 @@ mod filters {
             .and_then(handlers::get_target)
     }
 
+    /// GET /targets/schedule
+    pub fn get_target_schedule(
+        game: Game,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        warp::path!("targets" / "schedule")
+            .and(warp::get())
+            .and(with_game(game))
+            .and_then(handlers::get_target_schedule)
+    }
+
     /// POST /targets
     pub fn create_target(
         game: Game,
     ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
         warp::path!("targets")
             .and(warp::post())
-            .and(extract_coordinate_from_json())
+            .and(extract_target_order_from_json())
             .and(with_game(game))
             .and_then(handlers::create_target)
     }
@@ -166,11 +321,42 @@ mod filters {
             .and_then(handlers::delete_target)
     }
 
-    /// ****** ******* *    * ***** ***** *******
-    /// *    *    *    *    * *     *   *  **    
-    /// *    *    *    ****** ***** ****     **  
-    /// *    *    *    *    * *     *  **      **
-    /// ******    *    *    * ***** *   * *******
+    /// GET /spotters
+    pub fn get_spotters(
+        game: Game,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        warp::path!("spotters")
+            .and(warp::get())
+            .and(with_game(game))
+            .and_then(handlers::get_spotters)
+    }
+
+    /// POST /spotters
+    pub fn create_spotter(
+        game: Game,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        warp::path!("spotters")
+            .and(warp::post())
+            .and(extract_coordinate_from_json())
+            .and(with_game(game))
+            .and_then(handlers::create_spotter)
+    }
+
+    /// DELETE /spotters
+    pub fn delete_spotter(
+        game: Game,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        warp::path!("spotters")
+            .and(warp::delete())
+            .and(with_game(game))
+            .and_then(handlers::delete_spotter)
+    }
+
+    // ****** ******* *    * ***** ***** *******
+    // *    *    *    *    * *     *   *  **    
+    // *    *    *    ****** ***** ****     **  
+    // *    *    *    *    * *     *  **      **
+    // ******    *    *    * ***** *   * *******
 
     /// GET /game
     pub fn get_game_config(
@@ -181,6 +367,47 @@ mod filters {
             .and(with_game(game))
             .and_then(handlers::get_game_config)
     }
+
+    /// POST /game/config
+    pub fn configure_game(
+        game: Game,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        warp::path!("game" / "config")
+            .and(warp::post())
+            .and(extract_config_from_json())
+            .and(with_game(game))
+            .and_then(handlers::configure_game)
+    }
+
+    /// GET /game/report
+    pub fn get_turn_report(
+        game: Game,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        warp::path!("game" / "report")
+            .and(warp::get())
+            .and(with_game(game))
+            .and_then(handlers::get_turn_report)
+    }
+
+    /// GET /game/objectives
+    pub fn get_objectives(
+        game: Game,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        warp::path!("game" / "objectives")
+            .and(warp::get())
+            .and(with_game(game))
+            .and_then(handlers::get_objectives)
+    }
+
+    /// GET /game/map
+    pub fn get_map(
+        game: Game,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        warp::path!("game" / "map")
+            .and(warp::get())
+            .and(with_game(game))
+            .and_then(handlers::get_map)
+    }
     
     /// POST /game/run
     pub fn run_turn(
@@ -193,6 +420,14 @@ mod filters {
             .and_then(handlers::run_turn)
     }
 
+    /// POST /maps/generate
+    pub fn generate_map() -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        warp::path!("maps" / "generate")
+            .and(warp::post())
+            .and(extract_map_params_from_json())
+            .and_then(handlers::generate_map)
+    }
+
     /// `with_game` is an internal filter which clones the gamestate
     /// for each operation on an endpoint.
     fn with_game(game: Game) -> impl Filter<Extract = (Game,), Error = std::convert::Infallible> + Clone {
@@ -204,7 +439,41 @@ mod filters {
         warp::body::json()
     }
 
+    /// `extract_unit_order_from_json` is an internal filter which parses a `UnitOrder` from a json
+    /// payload.
+    fn extract_unit_order_from_json() -> impl Filter<Extract = (UnitOrder,), Error = warp::Rejection> + Clone {
+        warp::body::json()
+    }
+
+    /// `extract_group_order_from_json` is an internal filter which parses a `GroupOrder` from a
+    /// json payload.
+    fn extract_group_order_from_json() -> impl Filter<Extract = (GroupOrder,), Error = warp::Rejection> + Clone {
+        warp::body::json()
+    }
+
+    /// `extract_stance_order_from_json` is an internal filter which parses a `StanceOrder` from a
+    /// json payload.
+    fn extract_stance_order_from_json() -> impl Filter<Extract = (StanceOrder,), Error = warp::Rejection> + Clone {
+        warp::body::json()
+    }
+
+    /// `extract_target_order_from_json` is an internal filter which parses a `TargetOrder` from a
+    /// json payload.
+    fn extract_target_order_from_json() -> impl Filter<Extract = (TargetOrder,), Error = warp::Rejection> + Clone {
+        warp::body::json()
+    }
+
+    /// `extract_config_from_json` is an internal filter which parses a partial `GameConfig` from a
+    /// json payload.
+    fn extract_config_from_json() -> impl Filter<Extract = (GameConfig,), Error = warp::Rejection> + Clone {
+        warp::body::json()
+    }
 
+    /// `extract_map_params_from_json` is an internal filter which parses `MapParams` from a json
+    /// payload.
+    fn extract_map_params_from_json() -> impl Filter<Extract = (MapParams,), Error = warp::Rejection> + Clone {
+        warp::body::json()
+    }
 }
 
 mod handlers {
@@ -212,34 +481,92 @@ mod handlers {
     use std::collections::HashMap;
     
     use crate::Game;
-    use crate::game::Coordinate;
-    
+    use crate::game::{Coordinate, Formation, GameConfig, GroupOrder, StanceOrder, TargetOrder, UnitOrder};
+    use crate::mapgen::{self, MapParams};
     
     use warp::http::StatusCode;
 
-    /// *   * **   * ***** ******* ******
-    /// *   * * *  *   *      *    **
-    /// *   * *  * *   *      *      **
-    /// *   * *   **   *      *        **
-    /// ***** *    * *****    *    ******
+    // *   * **   * ***** ******* ******
+    // *   * * *  *   *      *    **
+    // *   * *  * *   *      *      **
+    // *   * *   **   *      *        **
+    // ***** *    * *****    *    ******
     
-    /// `handlers::get_all_units` returns a list of all unit positions using `Game.get_units`
-    /// Also includes all unit destinations using `Game.get_destinations`
+    /// `handlers::get_all_units` returns the positions of every unit the artillery player can see
+    /// using `Game.get_visible_unit_indexes`, along with their health. Hidden units are left out
+    /// (see `handlers::get_visible_units` for their last known positions)
     pub async fn get_all_units(game: Game) -> Result<impl warp::Reply, Infallible> {
         let mut gamestate = game.lock().await;
         let mut response: HashMap<&str, String> = HashMap::new();
 
+        let visible = gamestate.get_visible_unit_indexes();
+        let positions: Vec<Coordinate> = visible.iter().map(|index| gamestate.get_units()[*index].clone()).collect();
+        let health: Vec<f32> = visible.iter().map(|index| gamestate.get_health()[*index]).collect();
+        response.insert("positions", serde_json::to_string(&positions).unwrap());
+        response.insert("health", serde_json::to_string(&health).unwrap());
+
+        Ok(warp::reply::json(&response))
+    }
+
+    /// `handlers::get_unit` returns the position of the unit at `index` in the list returned by
+    /// `handlers::get_all_units` using `Game.get_visible_unit`, along with its health
+    pub async fn get_unit(index: usize, game: Game) -> Result<impl warp::Reply, Infallible> {
+        let mut gamestate = game.lock().await;
+        let mut response: HashMap<&str, String> = HashMap::new();
+
+        match gamestate.get_visible_unit(index) {
+            Ok(unit_index) => {
+                response.insert("position", serde_json::to_string(&gamestate.get_units()[unit_index]).unwrap());
+                response.insert("health", serde_json::to_string(&gamestate.get_health()[unit_index]).unwrap());
+
+                Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::OK))
+            }
+            Err(error) => {
+                // `Game.get_visible_unit` fails when the artillery player can't see that many units
+                response.insert("error", serde_json::to_string(&error).unwrap());
+
+                Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::NOT_FOUND))
+            }
+        }
+    }
+
+    /// `handlers::get_army_units` returns a list of all unit positions using `Game.get_units`, for
+    /// the army player
+    /// Also includes all unit destinations using `Game.get_destinations`, every unit's orders, and
+    /// how many soldiers the army has lost
+    pub async fn get_army_units(game: Game) -> Result<impl warp::Reply, Infallible> {
+        let mut gamestate = game.lock().await;
+        let mut response: HashMap<&str, String> = HashMap::new();
+
         response.insert("positions",
-                        serde_json::to_string(&&gamestate.get_units().clone()).unwrap());
+                        serde_json::to_string(&gamestate.get_units().clone()).unwrap());
         response.insert("destinations",
-                        serde_json::to_string(&&gamestate.get_destinations().clone()).unwrap());
+                        serde_json::to_string(&gamestate.get_destinations().clone()).unwrap());
+        response.insert("health",
+                        serde_json::to_string(&gamestate.get_health().clone()).unwrap());
+        response.insert("kinds",
+                        serde_json::to_string(&gamestate.get_unit_kinds().clone()).unwrap());
+        response.insert("camouflaged",
+                        serde_json::to_string(&gamestate.get_camouflaged().clone()).unwrap());
+        response.insert("raiding",
+                        serde_json::to_string(&gamestate.get_raiding().clone()).unwrap());
+        response.insert("stances",
+                        serde_json::to_string(&gamestate.get_stances().clone()).unwrap());
+        response.insert("fatigued",
+                        serde_json::to_string(&gamestate.get_fatigued().clone()).unwrap());
+        response.insert("routes",
+                        serde_json::to_string(&gamestate.get_routes().clone()).unwrap());
+        // Losses are counted by kind, so only the army player may see them
+        response.insert("soldiers_lost", serde_json::to_string(&gamestate.get_soldiers_lost()).unwrap());
+        response.insert("soldiers_consumed", serde_json::to_string(&gamestate.get_soldiers_consumed()).unwrap());
 
         Ok(warp::reply::json(&response))
     }
 
-    /// `handlers::get_unit` returns a unit's position at `index` in the list using `Game.get_unit`
-    /// Also includes the unit's destination using `Game.get_destination`
-    pub async fn get_unit(index: usize, game: Game) -> Result<impl warp::Reply, Infallible> {
+    /// `handlers::get_army_unit` returns a unit's position at `index` in the list using
+    /// `Game.get_unit`, for the army player
+    /// Also includes the unit's destination using `Game.get_destination`, and its orders
+    pub async fn get_army_unit(index: usize, game: Game) -> Result<impl warp::Reply, Infallible> {
         let mut gamestate = game.lock().await;
         let mut response: HashMap<&str, String> = HashMap::new();
 
@@ -251,34 +578,58 @@ mod handlers {
                     serde_json::to_string(&
                         gamestate
                         .get_destination(index)
-                        .expect(format!("If a unit exists at {}, then a destination must exist at {}", index, index).as_str())
+                        .unwrap_or_else(|_| panic!("If a unit exists at {}, then a destination must exist at {}", index, index))
                         .clone()
                     ).unwrap(),
                 );
-
-                return Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::OK));
+                response.insert("health", serde_json::to_string(&gamestate.get_health()[index]).unwrap());
+                response.insert("kind", serde_json::to_string(&gamestate.get_unit_kinds()[index]).unwrap());
+                response.insert("camouflaged", serde_json::to_string(&gamestate.get_camouflaged()[index]).unwrap());
+                response.insert("raiding", serde_json::to_string(&gamestate.get_raiding()[index]).unwrap());
+                response.insert("stance", serde_json::to_string(&gamestate.get_stances()[index]).unwrap());
+                response.insert("fatigued", serde_json::to_string(&gamestate.get_fatigued()[index]).unwrap());
+                response.insert("route", serde_json::to_string(&gamestate.get_routes()[index]).unwrap());
+
+                Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::OK))
             }
             Err(error) => {
                 // `Game.get_unit` currently only fails when a unit doesn't exist at that index
                 response.insert("error", serde_json::to_string(&error).unwrap());
 
-                return Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::NOT_FOUND));
+                Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::NOT_FOUND))
             }
         }
     }
 
-    /// `handlers::create_unit` creates a unit at the specified position using `Game.add_unit`
+    /// `handlers::get_visible_units` returns the positions of every unit the artillery player can
+    /// see using `Game.get_visible_units`, the last known positions of the rest using
+    /// `Game.get_last_known_units`, and the smoke clouds hiding units
+    pub async fn get_visible_units(game: Game) -> Result<impl warp::Reply, Infallible> {
+        let mut gamestate = game.lock().await;
+        let mut response: HashMap<&str, String> = HashMap::new();
+
+        response.insert("positions", serde_json::to_string(&gamestate.get_visible_units()).unwrap());
+        response.insert("last_known", serde_json::to_string(&gamestate.get_last_known_units()).unwrap());
+        response.insert("smoke_clouds", serde_json::to_string(&gamestate.get_smoke_clouds().clone()).unwrap());
+
+        Ok(warp::reply::json(&response))
+    }
+
+    /// `handlers::create_unit` creates a unit of the specified kind at the specified position
+    /// using `Game.add_unit`
     /// There aren't any rules regarding unit limits; maybe that's the client's job
-    pub async fn create_unit(coordinate: Coordinate, game: Game) -> Result<impl warp::Reply, Infallible> {
+    pub async fn create_unit(order: UnitOrder, game: Game) -> Result<impl warp::Reply, Infallible> {
         let mut gamestate = game.lock().await;
         let mut response: HashMap<&str, String> = HashMap::new();
 
-        match gamestate.add_unit(coordinate.x, coordinate.y) {
+        match gamestate.add_unit(order.x, order.y, order.kind) {
             Ok(_) => {
+                let coordinate = Coordinate { x: order.x, y: order.y };
                 response.insert("coordinate",
-                                serde_json::to_string(&coordinate.clone()).unwrap());
+                                serde_json::to_string(&coordinate).unwrap());
+                response.insert("kind", serde_json::to_string(&order.kind).unwrap());
 
-                return Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::CREATED));
+                Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::CREATED))
             },
             Err(error) => { // Currently only fails when unit is outside map, or inside the base
                 response.insert("error", serde_json::to_string(&error).unwrap());
@@ -294,50 +645,164 @@ mod handlers {
         let mut response: HashMap<&str, String> = HashMap::new();
 
         match gamestate.remove_unit(index) {
-            Ok(_) => { return Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::NO_CONTENT)); },
+            Ok(_) => { Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::NO_CONTENT)) },
             Err(error) => { // `Game.remove_unit` currently only fails when index DNE
                 response.insert("error", serde_json::to_string(&error).unwrap());
 
-                return Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::NOT_FOUND));
+                Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::NOT_FOUND))
             }
         }
     }
 
-    /// `handlers::set_destination` sets a unit's destination at a particular `index`
+    /// `handlers::set_destination` sets a unit's destination at a particular `index`, and returns
+    /// the route it will take, along with the point it will reach this turn
+    /// Pathfinding runs on a blocking thread, since routes across a lot of terrain take a while
     pub async fn set_destination(index: usize, coordinate: Coordinate, game: Game) -> Result<impl warp::Reply, Infallible> {
+        let (response, status) = tokio::task::spawn_blocking(move || {
+            let mut gamestate = game.blocking_lock();
+            let mut response: HashMap<&str, String> = HashMap::new();
+
+            match gamestate.set_destination(index, coordinate.x, coordinate.y) {
+                Ok(path) => {
+                    response.insert("index", serde_json::to_string(&index).unwrap());
+                    response.insert("coordinate", serde_json::to_string(&coordinate).unwrap());
+                    response.insert("destination", serde_json::to_string(&gamestate.get_destinations()[index]).unwrap());
+                    response.insert("path", serde_json::to_string(&path).unwrap());
+
+                    (response, StatusCode::CREATED)
+                }
+                Err(error) => {
+                    response.insert("error", serde_json::to_string(&error).unwrap());
+
+                    (response, StatusCode::BAD_REQUEST)
+                }
+            }
+        })
+        .await
+        .expect("`Game::set_destination` panicked in `handler::set_destination`");
+
+        Ok(warp::reply::with_status(warp::reply::json(&response), status))
+    }
+
+    /// `handlers::set_group_destination` routes several units to their own slots in a formation
+    /// around a destination using `Game.set_group_destination`, and returns where each unit was
+    /// sent, along with the units that couldn't comply
+    /// Pathfinding runs on a blocking thread, since routes across a lot of terrain take a while
+    pub async fn set_group_destination(order: GroupOrder, game: Game) -> Result<impl warp::Reply, Infallible> {
+        let (response, status) = tokio::task::spawn_blocking(move || {
+            let mut gamestate = game.blocking_lock();
+            let mut response: HashMap<&str, String> = HashMap::new();
+
+            let spacing = order.spacing.unwrap_or(Formation::DEFAULT_SPACING);
+            match gamestate.set_group_destination(&order.units, order.x, order.y, order.formation, spacing) {
+                Ok(moves) => {
+                    let failed: Vec<usize> = moves.iter().filter(|group_move| group_move.error.is_some()).map(|group_move| group_move.unit).collect();
+                    response.insert("coordinate", serde_json::to_string(&Coordinate { x: order.x, y: order.y }).unwrap());
+                    response.insert("formation", serde_json::to_string(&order.formation).unwrap());
+                    response.insert("spacing", serde_json::to_string(&spacing).unwrap());
+                    response.insert("moves", serde_json::to_string(&moves).unwrap());
+                    response.insert("failed", serde_json::to_string(&failed).unwrap());
+
+                    (response, StatusCode::CREATED)
+                }
+                Err(error) => {
+                    response.insert("error", serde_json::to_string(&error).unwrap());
+
+                    (response, StatusCode::BAD_REQUEST)
+                }
+            }
+        })
+        .await
+        .expect("`Game::set_group_destination` panicked in `handler::set_group_destination`");
+
+        Ok(warp::reply::with_status(warp::reply::json(&response), status))
+    }
+
+    /// `handlers::camouflage_unit` camouflages the unit at a particular `index` until the next turn
+    /// has been played using `Game.set_camouflage`
+    pub async fn camouflage_unit(index: usize, game: Game) -> Result<impl warp::Reply, Infallible> {
         let mut gamestate = game.lock().await;
         let mut response: HashMap<&str, String> = HashMap::new();
 
-        match gamestate.set_destination(index, coordinate.x, coordinate.y) {
-            Ok(_) => {
-                response.insert("index", serde_json::to_string(&index).unwrap());
-                response.insert("coordinate", serde_json::to_string(&coordinate).unwrap());
+        match gamestate.set_camouflage(index) {
+            Ok(_) => Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::NO_CONTENT)),
+            Err(error) => { // `Game.set_camouflage` currently only fails when index DNE
+                response.insert("error", serde_json::to_string(&error).unwrap());
 
-                return Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::CREATED));
+                Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::NOT_FOUND))
             }
-            Err(error) => {
+        }
+    }
+
+    /// `handlers::raid_unit` orders the unit at a particular `index` to raid the artillery this
+    /// turn using `Game.set_raid`
+    pub async fn raid_unit(index: usize, game: Game) -> Result<impl warp::Reply, Infallible> {
+        let mut gamestate = game.lock().await;
+        let mut response: HashMap<&str, String> = HashMap::new();
+
+        match gamestate.set_raid(index) {
+            Ok(_) => Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::NO_CONTENT)),
+            Err(error) => { // `Game.set_raid` currently only fails when index DNE
                 response.insert("error", serde_json::to_string(&error).unwrap());
 
-                return Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::BAD_REQUEST));
+                Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::NOT_FOUND))
             }
         }
     }
 
+    /// `handlers::set_stance` sets the stance of the unit at a particular `index` for the rest of
+    /// the turn using `Game.set_stance`, and returns the unit's new destination and route
+    /// Pathfinding runs on a blocking thread, since routes across a lot of terrain take a while
+    pub async fn set_stance(index: usize, order: StanceOrder, game: Game) -> Result<impl warp::Reply, Infallible> {
+        let (response, status) = tokio::task::spawn_blocking(move || {
+            let mut gamestate = game.blocking_lock();
+            let mut response: HashMap<&str, String> = HashMap::new();
+
+            match gamestate.set_stance(index, order.stance) {
+                Ok(_) => {
+                    response.insert("index", serde_json::to_string(&index).unwrap());
+                    response.insert("stance", serde_json::to_string(&order.stance).unwrap());
+                    response.insert("destination", serde_json::to_string(&gamestate.get_destinations()[index]).unwrap());
+                    response.insert("route", serde_json::to_string(&gamestate.get_routes()[index]).unwrap());
+
+                    (response, StatusCode::CREATED)
+                }
+                Err(error) => {
+                    response.insert("error", serde_json::to_string(&error).unwrap());
+
+                    (response, StatusCode::BAD_REQUEST)
+                }
+            }
+        })
+        .await
+        .expect("`Game::set_stance` panicked in `handler::set_stance`");
+
+        Ok(warp::reply::with_status(warp::reply::json(&response), status))
+    }
+
 
-    /// *******  *     ***** ***** ***** ******* *******
-    ///    *    * *    *   * *     *        *     **
-    ///    *   *****   ****  * *** *****    *       **
-    ///    *  *     *  *  ** *   * *        *         **
-    ///    * *       * *   * ***** *****    *    *******
+    // *******  *     ***** ***** ***** ******* *******
+    //    *    * *    *   * *     *        *     **
+    //    *   *****   ****  * *** *****    *       **
+    //    *  *     *  *  ** *   * *        *         **
+    //    * *       * *   * ***** *****    *    *******
 
     /// `handlers::get_all_targets` returns list of target positions using `Game.get_targets`
-    /// Also includes the current target costs using `Game.get_target_costs`
+    /// Also includes the current target costs using `Game.get_target_costs`, the battery firing
+    /// at each target, and each battery's position and resources
     pub async fn get_all_targets(game: Game) -> Result<impl warp::Reply, Infallible> {
         let mut gamestate = game.lock().await;
         let mut response: HashMap<&str, String> = HashMap::new();
 
         response.insert("targets", serde_json::to_string(&gamestate.get_targets().clone()).unwrap());
         response.insert("target_costs", serde_json::to_string(&gamestate.get_target_costs().clone()).unwrap());
+        response.insert("munitions", serde_json::to_string(&gamestate.get_target_munitions().clone()).unwrap());
+        response.insert("target_batteries", serde_json::to_string(&gamestate.get_target_batteries().clone()).unwrap());
+        response.insert("shapes", serde_json::to_string(&gamestate.get_target_shapes().clone()).unwrap());
+        response.insert("batteries", serde_json::to_string(gamestate.get_batteries()).unwrap());
+        response.insert("disabled_batteries", serde_json::to_string(gamestate.get_disabled_batteries()).unwrap());
+        response.insert("resources", serde_json::to_string(&gamestate.get_all_available_resources()).unwrap());
+        response.insert("banked_resources", serde_json::to_string(&gamestate.get_banked_resources()).unwrap());
 
         Ok(warp::reply::json(&response))
     }
@@ -356,42 +821,59 @@ mod handlers {
                     serde_json::to_string(&
                             gamestate
                             .get_target_cost(index)
-                            .expect(format!("If a target exists at {}, then a cost must exist at {}", index, index).as_str())
+                            .unwrap_or_else(|_| panic!("If a target exists at {}, then a cost must exist at {}", index, index))
                             .clone()
                     ).unwrap()
                 );
+                response.insert("munition", serde_json::to_string(&gamestate.get_target_munitions()[index]).unwrap());
+                response.insert("battery", serde_json::to_string(&gamestate.get_target_batteries()[index]).unwrap());
+                response.insert("shape", serde_json::to_string(&gamestate.get_target_shapes()[index]).unwrap());
 
-                return Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::CREATED));
+                Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::CREATED))
             }
             // `Game.get_target` currently only fails when the index DNE
             Err(error) => {
                 response.insert("error", serde_json::to_string(&error).unwrap());
 
-                return Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::NOT_FOUND));
+                Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::NOT_FOUND))
             }
         }
     }
 
-    /// `handlers::create_target` creates a target at the specified position using
-    /// `Game.add_target`
-    pub async fn create_target(coordinate: Coordinate, game: Game) -> Result<impl warp::Reply, Infallible> {
+    /// `handlers::get_target_schedule` returns a preview of when each target will be fired, and
+    /// when it will land, using `Game.get_target_schedule`
+    pub async fn get_target_schedule(game: Game) -> Result<impl warp::Reply, Infallible> {
+        let mut gamestate = game.lock().await;
+        let mut response: HashMap<&str, String> = HashMap::new();
+
+        response.insert("schedule", serde_json::to_string(&gamestate.get_target_schedule()).unwrap());
+
+        Ok(warp::reply::json(&response))
+    }
+
+    /// `handlers::create_target` creates a target at the specified position, with the specified
+    /// munition, shape and battery, using `Game.add_target`
+    pub async fn create_target(order: TargetOrder, game: Game) -> Result<impl warp::Reply, Infallible> {
         let mut gamestate = game.lock().await;
         let mut response: HashMap<&str, String> = HashMap::new();
 
-        match gamestate.add_target(coordinate.x, coordinate.y) {
+        match gamestate.add_target(order.battery, order.x, order.y, order.munition, order.shape.clone()) {
             Ok(_) => { 
-                let coordinate = Coordinate { x: coordinate.x, y: coordinate.y };
+                let coordinate = Coordinate { x: order.x, y: order.y };
                 response.insert("target", serde_json::to_string(&coordinate).unwrap());
+                response.insert("munition", serde_json::to_string(&order.munition).unwrap());
+                response.insert("battery", serde_json::to_string(&order.battery).unwrap());
+                response.insert("shape", serde_json::to_string(&order.shape).unwrap());
                 let index = gamestate.get_targets().len() - 1;
-                let target_cost = gamestate.get_target_cost(index).expect("Target was just created by `create_target`").clone();
+                let target_cost = *gamestate.get_target_cost(index).expect("Target was just created by `create_target`");
                 response.insert("cost", serde_json::to_string(&target_cost).unwrap());
 
-                return Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::CREATED));
+                Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::CREATED))
             }
-            Err(error) => { // Fails when target is out of map, and not enough resources
+            Err(error) => { // Fails when target is out of map, the battery DNE, the barrage is invalid, and not enough resources
                 response.insert("error", serde_json::to_string(&error).unwrap());
 
-                return Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::FORBIDDEN));
+                Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::FORBIDDEN))
             },
         }
     }
@@ -402,21 +884,72 @@ mod handlers {
         let mut response: HashMap<&str, String> = HashMap::new();
 
         match gamestate.remove_newest_target() {
-            Ok(_) => { return Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::NO_CONTENT)); },
+            Ok(_) => { Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::NO_CONTENT)) },
             Err(error) => {
                 response.insert("error", serde_json::to_string(&error).unwrap());
 
-                return Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::FORBIDDEN));
+                Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::FORBIDDEN))
             }
         }
 
     }
 
-    /// ****** ******* *    * ***** ***** *******
-    /// *    *    *    *    * *     *   *  **    
-    /// *    *    *    ****** ***** ****     **
-    /// *    *    *    *    * *     *  **      **
-    /// ******    *    *    * ***** *   * *******
+    /// `handlers::get_spotters` returns the active spotters using `Game.get_spotters`, and the
+    /// spotters ordered this turn using `Game.get_spotter_orders`
+    pub async fn get_spotters(game: Game) -> Result<impl warp::Reply, Infallible> {
+        let mut gamestate = game.lock().await;
+        let mut response: HashMap<&str, String> = HashMap::new();
+
+        response.insert("spotters", serde_json::to_string(&gamestate.get_spotters().clone()).unwrap());
+        response.insert("orders", serde_json::to_string(&gamestate.get_spotter_orders().clone()).unwrap());
+        response.insert("recon", serde_json::to_string(gamestate.get_recon()).unwrap());
+        response.insert("resources", serde_json::to_string(&gamestate.get_available_resources(0)).unwrap());
+
+        Ok(warp::reply::json(&response))
+    }
+
+    /// `handlers::create_spotter` orders a spotter at the specified position using
+    /// `Game.add_spotter`
+    pub async fn create_spotter(coordinate: Coordinate, game: Game) -> Result<impl warp::Reply, Infallible> {
+        let mut gamestate = game.lock().await;
+        let mut response: HashMap<&str, String> = HashMap::new();
+
+        match gamestate.add_spotter(coordinate.x, coordinate.y) {
+            Ok(_) => {
+                response.insert("spotter", serde_json::to_string(&coordinate).unwrap());
+                response.insert("cost", serde_json::to_string(&gamestate.get_recon().spotter_cost).unwrap());
+
+                Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::CREATED))
+            }
+            Err(error) => { // Fails when spotter is out of map, and not enough resources
+                response.insert("error", serde_json::to_string(&error).unwrap());
+
+                Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::FORBIDDEN))
+            },
+        }
+    }
+
+    /// `handlers::delete_spotter` cancels the newest spotter order using
+    /// `Game.remove_newest_spotter`
+    pub async fn delete_spotter(game: Game) -> Result<impl warp::Reply, Infallible> {
+        let mut gamestate = game.lock().await;
+        let mut response: HashMap<&str, String> = HashMap::new();
+
+        match gamestate.remove_newest_spotter() {
+            Ok(_) => Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::NO_CONTENT)),
+            Err(error) => {
+                response.insert("error", serde_json::to_string(&error).unwrap());
+
+                Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::FORBIDDEN))
+            }
+        }
+    }
+
+    // ****** ******* *    * ***** ***** *******
+    // *    *    *    *    * *     *   *  **    
+    // *    *    *    ****** ***** ****     **
+    // *    *    *    *    * *     *  **      **
+    // ******    *    *    * ***** *   * *******
     
     /// `handlers::get_game_config` returns all of 'settings' for the currently running `Game`
     pub async fn get_game_config(game: Game) -> Result<impl warp::Reply, Infallible> {
@@ -428,17 +961,124 @@ mod handlers {
         response.insert("base_coords", serde_json::to_string(&gamestate.get_base_coords().clone()).unwrap());
         response.insert("base_radius", serde_json::to_string(&gamestate.get_base_radius().clone()).unwrap());
         response.insert("max_unit_range", serde_json::to_string(&gamestate.get_max_unit_range().clone()).unwrap());
+        response.insert("unit_footprint", serde_json::to_string(&gamestate.get_unit_footprint()).unwrap());
         response.insert("max_resources", serde_json::to_string(&gamestate.get_max_resources().clone()).unwrap());
         response.insert("game_over", serde_json::to_string(&gamestate.get_game_over().clone()).unwrap());
         response.insert("minimum_unit_radius", serde_json::to_string(&gamestate.get_minimum_unit_radius().clone()).unwrap());
+        response.insert("spawn_region", serde_json::to_string(gamestate.get_spawn_region()).unwrap());
+        response.insert("map_origin", serde_json::to_string(&gamestate.get_map().origin).unwrap());
+        response.insert("turn", serde_json::to_string(&gamestate.get_turn()).unwrap());
+        response.insert("reinforcements", serde_json::to_string(gamestate.get_reinforcements()).unwrap());
+        response.insert("economy", serde_json::to_string(gamestate.get_economy()).unwrap());
+        response.insert("seed", serde_json::to_string(&gamestate.get_seed()).unwrap());
+        response.insert("dispersion", serde_json::to_string(&gamestate.get_dispersion()).unwrap());
+        response.insert("max_wind", serde_json::to_string(&gamestate.get_max_wind()).unwrap());
+        response.insert("wind", serde_json::to_string(gamestate.get_wind()).unwrap());
+        response.insert("flight_time", serde_json::to_string(&gamestate.get_flight_time()).unwrap());
+        response.insert("spotting_radius", serde_json::to_string(&gamestate.get_spotting_radius()).unwrap());
+        response.insert("recon", serde_json::to_string(gamestate.get_recon()).unwrap());
+        response.insert("objectives", serde_json::to_string(gamestate.get_objectives()).unwrap());
+        response.insert("victory", serde_json::to_string(gamestate.get_victory()).unwrap());
+        response.insert("siege", serde_json::to_string(gamestate.get_siege()).unwrap());
+        response.insert("suppression", serde_json::to_string(gamestate.get_suppression()).unwrap());
+        response.insert("batteries", serde_json::to_string(gamestate.get_batteries()).unwrap());
+        response.insert("defenses", serde_json::to_string(gamestate.get_defenses()).unwrap());
+        response.insert("disabled_batteries", serde_json::to_string(gamestate.get_disabled_batteries()).unwrap());
+        response.insert("counter_battery", serde_json::to_string(gamestate.get_counter_battery()).unwrap());
+        response.insert("stance_effects", serde_json::to_string(gamestate.get_stance_effects()).unwrap());
+        response.insert("base_health", serde_json::to_string(&gamestate.get_base_health()).unwrap());
 
         Ok(warp::reply::json(&response))
     }
 
-    pub async fn run_turn(game: Game) -> Result<impl warp::Reply, Infallible> {
+    /// `handlers::get_objectives` returns the objectives and the rules for winning with them using
+    /// `Game.get_objectives`, how long each objective has been held, and the army's score
+    pub async fn get_objectives(game: Game) -> Result<impl warp::Reply, Infallible> {
+        let gamestate = game.lock().await;
+
+        let mut response: HashMap<&str, String> = HashMap::new();
+        response.insert("objectives", serde_json::to_string(gamestate.get_objectives()).unwrap());
+        response.insert("ticks_held", serde_json::to_string(gamestate.get_objective_ticks()).unwrap());
+        response.insert("turns_held", serde_json::to_string(gamestate.get_objective_turns()).unwrap());
+        response.insert("army_score", serde_json::to_string(&gamestate.get_army_score()).unwrap());
+
+        Ok(warp::reply::json(&response))
+    }
+
+    /// `handlers::get_map` returns the shape of the map, its holes, and every terrain feature on it
+    /// using `Game.get_map`
+    pub async fn get_map(game: Game) -> Result<impl warp::Reply, Infallible> {
+        let gamestate = game.lock().await;
+        let map = gamestate.get_map();
+
+        let mut response: HashMap<&str, String> = HashMap::new();
+        response.insert("shape", serde_json::to_string(&map.shape).unwrap());
+        response.insert("origin", serde_json::to_string(&map.origin).unwrap());
+        response.insert("holes", serde_json::to_string(&map.holes).unwrap());
+        response.insert("terrain", serde_json::to_string(&map.terrain).unwrap());
+        response.insert("outposts", serde_json::to_string(&map.outposts).unwrap());
+        response.insert("elevation", serde_json::to_string(&map.elevation).unwrap());
+        response.insert("map_radius", serde_json::to_string(&gamestate.get_map_radius()).unwrap());
+        response.insert("base_coords", serde_json::to_string(gamestate.get_base_coords()).unwrap());
+        response.insert("spawn_region", serde_json::to_string(gamestate.get_spawn_region()).unwrap());
+
+        Ok(warp::reply::json(&response))
+    }
+
+    /// `handlers::configure_game` applies a partial configuration to the game using
+    /// `Game.configure`
+    pub async fn configure_game(config: GameConfig, game: Game) -> Result<impl warp::Reply, Infallible> {
         let mut gamestate = game.lock().await;
+        let mut response: HashMap<&str, String> = HashMap::new();
+
+        match gamestate.configure(config) {
+            Ok(_) => Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::NO_CONTENT)),
+            Err(error) => { // Fails when a setting has an invalid value
+                response.insert("error", serde_json::to_string(&error).unwrap());
+
+                Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::BAD_REQUEST))
+            }
+        }
+    }
+
+    /// `handlers::get_turn_report` returns the report for the last turn using
+    /// `Game.get_last_report`
+    pub async fn get_turn_report(game: Game) -> Result<impl warp::Reply, Infallible> {
+        let gamestate = game.lock().await;
+        let report = gamestate.get_last_report();
+
+        let mut response: HashMap<&str, String> = HashMap::new();
+        response.insert("turn", serde_json::to_string(&report.turn).unwrap());
+        response.insert("winner", serde_json::to_string(&report.winner).unwrap());
+        response.insert("reason", serde_json::to_string(&report.reason).unwrap());
+        response.insert("reinforcements", serde_json::to_string(&report.reinforcements).unwrap());
+        response.insert("units_destroyed", serde_json::to_string(&report.units_destroyed).unwrap());
+        response.insert("spotters_destroyed", serde_json::to_string(&report.spotters_destroyed).unwrap());
+        response.insert("units_consumed", serde_json::to_string(&report.units_consumed).unwrap());
+        response.insert("units_suppressed", serde_json::to_string(&report.units_suppressed).unwrap());
+        response.insert("turret_shots", serde_json::to_string(&report.turret_shots).unwrap());
+        response.insert("batteries_disabled", serde_json::to_string(&report.batteries_disabled).unwrap());
+        response.insert("spotters_disabled", serde_json::to_string(&report.spotters_disabled).unwrap());
+        response.insert("base_health", serde_json::to_string(&report.base_health).unwrap());
+        response.insert("objectives_held", serde_json::to_string(&report.objectives_held).unwrap());
+        response.insert("army_score", serde_json::to_string(&report.army_score).unwrap());
+        response.insert("banked_resources", serde_json::to_string(&report.banked_resources).unwrap());
+        response.insert("wind", serde_json::to_string(&report.wind).unwrap());
+        response.insert("impacts", serde_json::to_string(&report.impacts).unwrap());
+        response.insert("schedule", serde_json::to_string(&report.schedule).unwrap());
 
-        if let Ok(_) = gamestate.run_turn() {
+        Ok(warp::reply::json(&response))
+    }
+
+    /// `handlers::run_turn` plays out the turn using `Game.run_turn`
+    /// The turn runs on a blocking thread, since routing units around a lot of terrain takes a
+    /// while
+    pub async fn run_turn(game: Game) -> Result<impl warp::Reply, Infallible> {
+        let result = tokio::task::spawn_blocking(move || game.blocking_lock().run_turn())
+            .await
+            .expect("`Game::run_turn` panicked in `handler::run_turn`");
+
+        if result.is_ok() {
             Ok(StatusCode::RESET_CONTENT)
         }
         else {
@@ -446,4 +1086,27 @@ mod handlers {
         }
 
     }
+
+    /// `handlers::generate_map` returns a randomly generated map using `mapgen::generate`. The map
+    /// can be saved to a file and played with `--map`.
+    /// Generation runs on a blocking thread, since large maps take a while to check
+    pub async fn generate_map(params: MapParams) -> Result<impl warp::Reply, Infallible> {
+        let mut response: HashMap<&str, String> = HashMap::new();
+
+        let generated = tokio::task::spawn_blocking(move || mapgen::generate(&params))
+            .await
+            .expect("`mapgen::generate` panicked in `handler::generate_map`");
+        match generated {
+            Ok(map) => {
+                response.insert("map", serde_json::to_string(&map).unwrap());
+
+                Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::OK))
+            }
+            Err(reason) => { // Fails when a setting has an invalid value
+                response.insert("error", serde_json::to_string(&reason).unwrap());
+
+                Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::BAD_REQUEST))
+            }
+        }
+    }
 }
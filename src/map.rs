@@ -0,0 +1,457 @@
+use serde::{Serialize, Deserialize};
+
+use crate::game::Coordinate;
+
+// Shape definitions BEGIN
+/// `Shape` is an area of the map, described as a circle, an axis-aligned rectangle, or a polygon.
+///
+/// Rectangles are given by their lowest (`min`) and highest (`max`) corners. Polygons are given as
+/// a list of corners, in order. The last corner is joined back to the first, so it does not need
+/// to be repeated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Shape {
+    Circle { center: Coordinate, radius: f32 },
+    Rectangle { min: Coordinate, max: Coordinate },
+    Polygon { points: Vec<Coordinate> },
+}
+
+impl Shape {
+    /// `contains` accepts a `Coordinate`, and returns true if it lies inside (or on the edge of)
+    /// the shape.
+    ///
+    /// Polygons use the even-odd rule: a ray cast from the point crosses the polygon's edges an
+    /// odd number of times if, and only if, the point is inside.
+    pub fn contains(&self, point: &Coordinate) -> bool {
+        match self {
+            Shape::Circle { center, radius } => center.contains(point, *radius),
+            Shape::Rectangle { min, max } => {
+                (min.x..=max.x).contains(&point.x) && (min.y..=max.y).contains(&point.y)
+            }
+            Shape::Polygon { points } => {
+                let mut inside = false;
+                for (start, end) in edges(points) {
+                    if point.distance_to_segment(start, end) <= f32::EPSILON {
+                        return true; // Points on the edge count as inside
+                    }
+                    if (start.y > point.y) != (end.y > point.y) {
+                        let crossing_x = start.x + (point.y - start.y) / (end.y - start.y) * (end.x - start.x);
+                        if point.x < crossing_x {
+                            inside = !inside;
+                        }
+                    }
+                }
+                inside
+            }
+        }
+    }
+
+    /// `intersects_segment` accepts the `start` and `end` of a straight line, and returns true if
+    /// any part of that line lies inside the shape.
+    pub fn intersects_segment(&self, start: &Coordinate, end: &Coordinate) -> bool {
+        match self {
+            Shape::Circle { center, radius } => center.distance_to_segment(start, end) <= *radius,
+            Shape::Rectangle { .. } | Shape::Polygon { .. } => {
+                let points = self.corners();
+                self.contains(start)
+                    || self.contains(end)
+                    || edges(&points).any(|(edge_start, edge_end)| segments_intersect(start, end, edge_start, edge_end))
+            }
+        }
+    }
+
+    /// `corners` returns the corners of a rectangle or polygon, in order. Circles have no corners.
+    pub fn corners(&self) -> Vec<Coordinate> {
+        match self {
+            Shape::Circle { .. } => vec![],
+            Shape::Rectangle { min, max } => vec![
+                min.clone(),
+                Coordinate { x: max.x, y: min.y },
+                max.clone(),
+                Coordinate { x: min.x, y: max.y },
+            ],
+            Shape::Polygon { points } => points.clone(),
+        }
+    }
+
+    /// `farthest_distance` accepts a `Coordinate`, and returns the distance from it to the
+    /// farthest point of the shape.
+    pub fn farthest_distance(&self, from: &Coordinate) -> f32 {
+        match self {
+            Shape::Circle { center, radius } => from.distance(center) + radius,
+            Shape::Rectangle { .. } | Shape::Polygon { .. } => {
+                self.corners().iter().map(|corner| from.distance(corner)).fold(0.0, f32::max)
+            }
+        }
+    }
+
+    /// `is_valid` returns true if the shape describes a real area: circles need a positive radius,
+    /// rectangles need `min` to be below and to the left of `max`, and polygons need at least 3
+    /// corners.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Shape::Circle { radius, .. } => *radius > 0.0,
+            Shape::Rectangle { min, max } => min.x < max.x && min.y < max.y,
+            Shape::Polygon { points } => points.len() >= 3,
+        }
+    }
+}
+
+/// `edges` accepts the corners of a polygon, and returns an iterator over each of its edges as a
+/// pair of corners. The last corner is joined back to the first.
+fn edges(points: &[Coordinate]) -> impl Iterator<Item = (&Coordinate, &Coordinate)> {
+    points.iter().zip(points.iter().cycle().skip(1))
+}
+
+/// `orientation` returns the cross product of (q - p) and (r - p). The sign tells us which side of
+/// the line p -> q the point r is on, and 0.0 means the three points are in a line.
+fn orientation(p: &Coordinate, q: &Coordinate, r: &Coordinate) -> f32 {
+    (q.x - p.x) * (r.y - p.y) - (q.y - p.y) * (r.x - p.x)
+}
+
+/// `segments_cross` returns true if the line from `a1` to `a2` properly crosses the line from `b1`
+/// to `b2`. Lines that only touch, or overlap, do not count as crossing.
+fn segments_cross(a1: &Coordinate, a2: &Coordinate, b1: &Coordinate, b2: &Coordinate) -> bool {
+    let d1 = orientation(b1, b2, a1);
+    let d2 = orientation(b1, b2, a2);
+    let d3 = orientation(a1, a2, b1);
+    let d4 = orientation(a1, a2, b2);
+    ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0)) && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0))
+}
+
+/// `segments_intersect` returns true if the line from `a1` to `a2` crosses or touches the line
+/// from `b1` to `b2`.
+fn segments_intersect(a1: &Coordinate, a2: &Coordinate, b1: &Coordinate, b2: &Coordinate) -> bool {
+    segments_cross(a1, a2, b1, b2)
+        || a1.distance_to_segment(b1, b2) <= f32::EPSILON
+        || a2.distance_to_segment(b1, b2) <= f32::EPSILON
+        || b1.distance_to_segment(a1, a2) <= f32::EPSILON
+        || b2.distance_to_segment(a1, a2) <= f32::EPSILON
+}
+// Shape definitions END
+//
+// Spawn definitions BEGIN
+/// `SpawnRegion` is the ring where the army player may place units: between `inner_radius` and
+/// `outer_radius` of `center`.
+///
+/// The spawn region is independent of the base and of the map's origin, so the base can sit near
+/// an edge of the map while units spawn elsewhere.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpawnRegion {
+    pub center: Coordinate,
+    pub inner_radius: f32,
+    pub outer_radius: f32,
+}
+
+impl SpawnRegion {
+    /// `contains` accepts a `Coordinate`, and returns true if it lies within the ring.
+    pub fn contains(&self, coord: &Coordinate) -> bool {
+        let distance = self.center.distance(coord);
+        distance >= self.inner_radius && distance <= self.outer_radius
+    }
+}
+// Spawn definitions END
+//
+// Terrain definitions BEGIN
+/// `TerrainKind` is the type of a map feature.
+///
+/// - Rock, Wall -> Impassable. Units can't be placed inside them, or move through them.
+/// - Crater, Cover -> Passable. Units inside them take reduced blast damage.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TerrainKind {
+    Rock,
+    Wall,
+    Crater,
+    Cover,
+}
+
+impl TerrainKind {
+    /// `is_impassable` returns true if units cannot enter this kind of terrain.
+    pub fn is_impassable(&self) -> bool {
+        matches!(self, TerrainKind::Rock | TerrainKind::Wall)
+    }
+
+    /// `default_protection` returns the fraction of blast damage absorbed by this kind of terrain
+    /// when a feature doesn't set its own `protection`.
+    pub fn default_protection(&self) -> f32 {
+        match self {
+            TerrainKind::Rock | TerrainKind::Wall => 0.0,
+            TerrainKind::Crater => 0.5,
+            TerrainKind::Cover => 0.75,
+        }
+    }
+}
+
+/// `TerrainFeature` is a single feature on the map, such as a rock or a cover zone.
+///
+/// `protection` is the fraction (0.0 - 1.0) of blast damage absorbed by units inside the feature.
+/// A value of 1.0 means units inside take no blast damage. If it is missing, the kind's
+/// `default_protection` is used.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TerrainFeature {
+    pub kind: TerrainKind,
+    pub shape: Shape,
+    #[serde(default)]
+    pub protection: Option<f32>,
+}
+
+impl TerrainFeature {
+    /// `protection` returns the fraction of blast damage absorbed by units inside this feature.
+    pub fn protection(&self) -> f32 {
+        self.protection.unwrap_or(self.kind.default_protection())
+    }
+}
+// Terrain definitions END
+//
+// Elevation definitions BEGIN
+/// `Heightmap` is a grid of elevations covering the map.
+///
+/// `heights[row][column]` is the elevation at `origin.x + column * spacing`,
+/// `origin.y + row * spacing`. Elevations between grid points are interpolated, and points beyond
+/// the grid use the elevation at its nearest edge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Heightmap {
+    pub origin: Coordinate,
+    pub spacing: f32,
+    pub heights: Vec<Vec<f32>>,
+}
+
+impl Heightmap {
+    /// Range lost for every unit of elevation a unit climbs, on top of the distance it travels.
+    pub const CLIMB_COST: f32 = 2.0;
+    /// Height a ridge must rise above the line between a blast and a unit to give full shelter.
+    pub const RIDGE_COVER_HEIGHT: f32 = 5.0;
+    /// The most blast damage a ridge can absorb.
+    pub const MAX_RIDGE_PROTECTION: f32 = 0.75;
+    /// The smallest `spacing` a grid can have. Every movement and blast samples the ground every
+    /// half a grid cell (see `profile`), so finer grids get expensive quickly.
+    pub const MIN_SPACING: f32 = 1.0;
+
+    /// `height_at` accepts a `Coordinate`, and returns the elevation of the ground there.
+    pub fn height_at(&self, point: &Coordinate) -> f32 {
+        let rows = self.heights.len();
+        let columns = self.heights[0].len();
+        let column = ((point.x - self.origin.x) / self.spacing).clamp(0.0, (columns - 1) as f32);
+        let row = ((point.y - self.origin.y) / self.spacing).clamp(0.0, (rows - 1) as f32);
+        let (column_0, row_0) = (column.floor() as usize, row.floor() as usize);
+        let (column_1, row_1) = ((column_0 + 1).min(columns - 1), (row_0 + 1).min(rows - 1));
+        let (dx, dy) = (column - column_0 as f32, row - row_0 as f32);
+
+        let bottom = self.heights[row_0][column_0] * (1.0 - dx) + self.heights[row_0][column_1] * dx;
+        let top = self.heights[row_1][column_0] * (1.0 - dx) + self.heights[row_1][column_1] * dx;
+        bottom * (1.0 - dy) + top * dy
+    }
+
+    /// `climb` accepts the `start` and `end` of a straight line, and returns the total elevation
+    /// gained walking along it. Downhill stretches don't count.
+    pub fn climb(&self, start: &Coordinate, end: &Coordinate) -> f32 {
+        let heights = self.profile(start, end);
+        heights.windows(2).map(|pair| (pair[1] - pair[0]).max(0.0)).sum()
+    }
+
+    /// `ridge_protection` accepts the `center` of a blast and a unit's `position`, and returns the
+    /// fraction of blast damage absorbed by the ground between them.
+    ///
+    /// The ground shelters a unit by how far it rises above the straight line from the blast to
+    /// the unit, up to `MAX_RIDGE_PROTECTION` for a ridge of `RIDGE_COVER_HEIGHT` or more.
+    pub fn ridge_protection(&self, center: &Coordinate, position: &Coordinate) -> f32 {
+        let heights = self.profile(center, position);
+        let last = heights.len() - 1;
+        let ridge = heights
+            .iter()
+            .enumerate()
+            .map(|(step, height)| {
+                let sight_line = heights[0] + (heights[last] - heights[0]) * step as f32 / last as f32;
+                height - sight_line
+            })
+            .fold(0.0, f32::max);
+        (ridge / Heightmap::RIDGE_COVER_HEIGHT).min(1.0) * Heightmap::MAX_RIDGE_PROTECTION
+    }
+
+    /// `is_valid` returns true if the grid's `spacing` is at least `MIN_SPACING`, and it has at
+    /// least one row, and rows of equal, non-zero length.
+    pub fn is_valid(&self) -> bool {
+        self.spacing >= Heightmap::MIN_SPACING
+            && !self.heights.is_empty()
+            && !self.heights[0].is_empty()
+            && self.heights.iter().all(|row| row.len() == self.heights[0].len())
+    }
+
+    /// `profile` accepts the `start` and `end` of a straight line, and returns the elevation at
+    /// evenly spaced points along it, at most half a grid cell apart. Both ends are included.
+    fn profile(&self, start: &Coordinate, end: &Coordinate) -> Vec<f32> {
+        let steps = ((start.distance(end) / (self.spacing / 2.0)).ceil() as usize).max(1);
+        (0..=steps)
+            .map(|step| {
+                let fraction = step as f32 / steps as f32;
+                self.height_at(&Coordinate { x: start.x + (end.x - start.x) * fraction, y: start.y + (end.y - start.y) * fraction })
+            })
+            .collect()
+    }
+}
+// Elevation definitions END
+//
+// Map definitions BEGIN
+/// `Map` describes the playing area: its outer `shape`, any `holes` cut out of it, and the
+/// `terrain` features placed on it.
+///
+/// `origin` is the center of the map. It is independent of where the base sits, and is used as
+/// the default center of the spawn region. `base` optionally places the base for games played on
+/// this map, and `outposts` lists any further base sites. `spawn_region` optionally sets where
+/// units are placed. `elevation` optionally adds hills and ridges (see `Heightmap`).
+///
+/// Maps can be loaded from a JSON file with `Map::load`, for example:
+/// `{"shape": {"type": "rectangle", "min": {"x": -100, "y": -60}, "max": {"x": 100, "y": 60}},
+///   "holes": [{"type": "circle", "center": {"x": 50, "y": 0}, "radius": 10}],
+///   "terrain": [], "origin": {"x": 0, "y": 0}, "base": {"x": -90, "y": 0}}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Map {
+    pub shape: Shape,
+    #[serde(default)]
+    pub origin: Coordinate,
+    #[serde(default)]
+    pub base: Option<Coordinate>,
+    #[serde(default)]
+    pub outposts: Vec<Coordinate>,
+    #[serde(default)]
+    pub spawn_region: Option<SpawnRegion>,
+    #[serde(default)]
+    pub holes: Vec<Shape>,
+    #[serde(default)]
+    pub terrain: Vec<TerrainFeature>,
+    #[serde(default)]
+    pub elevation: Option<Heightmap>,
+}
+
+impl Map {
+    /// The most terrain features a map can have. Pathfinding checks every feature for each step
+    /// it considers (see `navigation::find_path`), so every feature slows down unit orders.
+    /// Generated maps stay within this (see `mapgen::generate`).
+    pub const MAX_TERRAIN: usize = 750;
+
+    /// `circle` accepts a `center` and a `radius`, and returns an empty, circular map with its
+    /// origin at `center`.
+    pub fn circle(center: Coordinate, radius: f32) -> Map {
+        Map {
+            shape: Shape::Circle { center: center.clone(), radius },
+            origin: center,
+            base: None,
+            outposts: vec![],
+            spawn_region: None,
+            holes: vec![],
+            terrain: vec![],
+            elevation: None,
+        }
+    }
+
+    /// `radius` returns the distance from the map's origin to its farthest point.
+    pub fn radius(&self) -> f32 {
+        self.shape.farthest_distance(&self.origin)
+    }
+
+    /// `load` accepts the `path` to a JSON map file, and parses and validates the map inside it.
+    ///
+    /// Returns the `Map`, or a message describing why it could not be loaded.
+    pub fn load(path: &str) -> Result<Map, String> {
+        let contents = std::fs::read_to_string(path).map_err(|error| format!("could not read {path}: {error}"))?;
+        let map: Map = serde_json::from_str(&contents).map_err(|error| format!("could not parse {path}: {error}"))?;
+        map.validate()?;
+        Ok(map)
+    }
+
+    /// `validate` checks that every shape on the map describes a real area (see
+    /// `Shape::is_valid`), that every terrain feature's protection is between 0.0 and 1.0, that
+    /// every outpost lies inside the map, that the spawn region is a valid ring, and that the
+    /// elevation grid is complete (see `Heightmap::is_valid`).
+    ///
+    /// Returns a message describing the first problem found.
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.shape.is_valid() {
+            return Err("the map's shape is not a valid area".to_string());
+        }
+        if !self.holes.iter().all(Shape::is_valid) {
+            return Err("every hole must be a valid area".to_string());
+        }
+        if self.terrain.len() > Map::MAX_TERRAIN {
+            return Err(format!("a map can have at most {} terrain features", Map::MAX_TERRAIN));
+        }
+        for feature in self.terrain.iter() {
+            if !feature.shape.is_valid() {
+                return Err("terrain shapes need a positive radius, or at least 3 points".to_string());
+            }
+            if !(0.0..=1.0).contains(&feature.protection()) {
+                return Err("terrain protection must be between 0.0 and 1.0".to_string());
+            }
+        }
+        if !self.outposts.iter().all(|outpost| self.contains(outpost)) {
+            return Err("every outpost must lie inside the map".to_string());
+        }
+        if let Some(spawn_region) = &self.spawn_region {
+            if spawn_region.inner_radius < 0.0 || spawn_region.inner_radius > spawn_region.outer_radius {
+                return Err("the spawn region's inner radius must be between 0 and its outer radius".to_string());
+            }
+        }
+        if self.elevation.as_ref().is_some_and(|elevation| !elevation.is_valid()) {
+            return Err(format!("the elevation grid needs a spacing of at least {}, and rows of equal, non-zero length", Heightmap::MIN_SPACING));
+        }
+        Ok(())
+    }
+
+    /// `contains` accepts a `Coordinate`, and returns true if it lies inside the map's shape and
+    /// outside all of its holes.
+    pub fn contains(&self, point: &Coordinate) -> bool {
+        self.shape.contains(point) && !self.holes.iter().any(|hole| hole.contains(point))
+    }
+
+    /// `is_passable` accepts a `Coordinate`, and returns false if it lies inside impassable
+    /// terrain.
+    pub fn is_passable(&self, point: &Coordinate) -> bool {
+        !self.terrain
+            .iter()
+            .any(|feature| feature.kind.is_impassable() && feature.shape.contains(point))
+    }
+
+    /// `is_path_clear` accepts the `start` and `end` of a straight line, and returns false if it
+    /// crosses impassable terrain.
+    pub fn is_path_clear(&self, start: &Coordinate, end: &Coordinate) -> bool {
+        !self.terrain
+            .iter()
+            .any(|feature| feature.kind.is_impassable() && feature.shape.intersects_segment(start, end))
+    }
+
+    /// `is_walkable` accepts the `start` and `end` of a straight line, and returns true if a unit
+    /// can walk along it: the line stays inside the map, and doesn't cross impassable terrain.
+    pub fn is_walkable(&self, start: &Coordinate, end: &Coordinate) -> bool {
+        self.contains_segment(start, end) && self.is_path_clear(start, end)
+    }
+
+    /// `path_cost` accepts the `start` and `end` of a straight line, and returns how much of a
+    /// unit's range it takes to walk along it.
+    ///
+    /// This is the length of the line, plus `Heightmap::CLIMB_COST` for every unit of elevation
+    /// climbed on the way. Maps without elevation are flat, so the cost is just the distance.
+    pub fn path_cost(&self, start: &Coordinate, end: &Coordinate) -> f32 {
+        let climb = self.elevation
+            .as_ref()
+            .map_or(0.0, |elevation| elevation.climb(start, end));
+        start.distance(end) + climb * Heightmap::CLIMB_COST
+    }
+
+    /// `contains_segment` accepts the `start` and `end` of a straight line, and returns true if
+    /// the whole line lies inside the map.
+    pub fn contains_segment(&self, start: &Coordinate, end: &Coordinate) -> bool {
+        if !self.contains(start) || !self.contains(end) {
+            return false;
+        }
+        if self.holes.iter().any(|hole| hole.intersects_segment(start, end)) {
+            return false;
+        }
+        match &self.shape {
+            // Circles and rectangles are convex, so any line between two inside points stays inside
+            Shape::Circle { .. } | Shape::Rectangle { .. } => true,
+            Shape::Polygon { points } => !edges(points).any(|(edge_start, edge_end)| segments_cross(start, end, edge_start, edge_end)),
+        }
+    }
+}
+// Map definitions END
@@ -0,0 +1,282 @@
+use std::collections::VecDeque;
+
+use serde::{Serialize, Deserialize};
+
+use crate::game::Coordinate;
+use crate::map::{Map, Shape, SpawnRegion, TerrainFeature, TerrainKind};
+use crate::rng::Rng;
+
+/// The spacing between the points used to check that the base can be reached. Units can always
+/// travel between neighbouring points on this grid, so it must be below the smallest unit range.
+const GRID_SPACING: f32 = 2.0;
+/// No obstacles are placed within this distance of a base or outpost.
+const BASE_CLEARANCE: f32 = 5.0;
+/// The most bases a generated map can have.
+const MAX_BASES: usize = 8;
+/// The most obstacles tried before giving up on reaching the requested density.
+const MAX_ATTEMPTS: usize = 500;
+/// The largest map radius that can be generated. The reachability grid grows with the square of
+/// the radius, and is scanned after every obstacle.
+const MAX_RADIUS: f32 = 250.0;
+
+/// `MapParams` are the settings for `generate`. Missing fields use their default values:
+///
+/// - seed = 0 -> The same seed (and settings) always produce the same map.
+/// - radius = 100 -> The radius of the circular map, centered on 0,0. At most `MAX_RADIUS`.
+/// - obstacle_density = 0.1 -> The fraction (0.0 - 0.5) of the map covered by rocks and walls.
+/// - bases = 1 -> The number of base sites. The first is the map's `base`, the rest are
+///   `outposts`.
+/// - spawn_ring_width = 10 -> The width of the spawn region, measured in from the map's edge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapParams {
+    pub seed: u64,
+    pub radius: f32,
+    pub obstacle_density: f32,
+    pub bases: usize,
+    pub spawn_ring_width: f32,
+}
+
+impl Default for MapParams {
+    fn default() -> Self {
+        MapParams { seed: 0, radius: 100.0, obstacle_density: 0.1, bases: 1, spawn_ring_width: 10.0 }
+    }
+}
+
+/// `generate` accepts `MapParams`, and builds a circular map with randomly placed bases, rocks,
+/// walls, craters and cover.
+///
+/// Obstacles are added one at a time, and any obstacle that would cut part of the spawn region, or
+/// any base, off from the first base is discarded. This guarantees that every unit placed in the
+/// spawn region has a path to every base, although the requested density may not be reached on
+/// crowded maps.
+///
+/// Returns the `Map`, or a message describing which setting is invalid.
+pub fn generate(params: &MapParams) -> Result<Map, String> {
+    if !(params.radius > 0.0 && params.radius <= MAX_RADIUS) {
+        return Err(format!("radius must be positive, and at most {MAX_RADIUS}"));
+    }
+    if !(0.0..=0.5).contains(&params.obstacle_density) {
+        return Err("obstacle_density must be between 0.0 and 0.5".to_string());
+    }
+    if !(1..=MAX_BASES).contains(&params.bases) {
+        return Err(format!("bases must be between 1 and {MAX_BASES}"));
+    }
+    if params.spawn_ring_width <= 0.0 || params.spawn_ring_width >= params.radius / 2.0 {
+        return Err("spawn_ring_width must be positive, and less than half of the radius".to_string());
+    }
+
+    let mut rng = Rng::new(params.seed);
+    let origin = Coordinate { x: 0.0, y: 0.0 };
+    let spawn_region = SpawnRegion {
+        center: origin.clone(),
+        inner_radius: params.radius - params.spawn_ring_width,
+        outer_radius: params.radius,
+    };
+
+    let bases = place_bases(&mut rng, params.bases, spawn_region.inner_radius / 2.0);
+    let mut map = Map::circle(origin, params.radius);
+    map.base = Some(bases[0].clone());
+    map.outposts = bases[1..].to_vec();
+    map.spawn_region = Some(spawn_region);
+
+    // Impassable obstacles, kept only while everything stays reachable
+    let mut grid = Grid::new(&map);
+    let target_area = params.obstacle_density * std::f32::consts::PI * params.radius * params.radius;
+    let mut covered_area = 0.0;
+    for _ in 0..MAX_ATTEMPTS {
+        if covered_area >= target_area {
+            break;
+        }
+        let (feature, area) = random_obstacle(&mut rng, params.radius);
+        if bases.iter().any(|base| feature.shape.contains(base) || near(&feature.shape, base)) {
+            continue;
+        }
+        let mut candidate = grid.clone();
+        candidate.block(&feature.shape);
+        if candidate.is_connected(&map, &bases) {
+            grid = candidate;
+            map.terrain.push(feature);
+            covered_area += area;
+        }
+    }
+
+    // Craters and cover don't block movement, so they can go anywhere
+    let obstacles = map.terrain.len();
+    for index in 0..obstacles.div_ceil(2) {
+        let kind = if index % 2 == 0 { TerrainKind::Cover } else { TerrainKind::Crater };
+        let offset = rng.point_in_circle(params.radius * 0.9);
+        let radius = 2.0 + rng.next_f32() * 4.0;
+        map.terrain.push(TerrainFeature { kind, shape: Shape::Circle { center: offset, radius }, protection: None });
+    }
+
+    map.validate()?;
+    Ok(map)
+}
+
+/// `place_bases` accepts the number of bases to place, and the radius around 0,0 to place them
+/// in. Bases are kept apart where possible, so that no two share the same clearance.
+fn place_bases(rng: &mut Rng, count: usize, radius: f32) -> Vec<Coordinate> {
+    let mut bases: Vec<Coordinate> = Vec::new();
+    while bases.len() < count {
+        let mut candidate = rng.point_in_circle(radius);
+        for _ in 0..MAX_ATTEMPTS {
+            if bases.iter().all(|base| base.distance(&candidate) >= BASE_CLEARANCE * 2.0) {
+                break;
+            }
+            candidate = rng.point_in_circle(radius);
+        }
+        bases.push(candidate);
+    }
+    bases
+}
+
+/// `random_obstacle` returns a rock or a wall somewhere on a map of the given `radius`, along with
+/// its area.
+fn random_obstacle(rng: &mut Rng, radius: f32) -> (TerrainFeature, f32) {
+    let center = rng.point_in_circle(radius);
+    if rng.next_f32() < 0.5 {
+        let rock_radius = 2.0 + rng.next_f32() * 6.0;
+        let shape = Shape::Circle { center, radius: rock_radius };
+        (TerrainFeature { kind: TerrainKind::Rock, shape, protection: None }, std::f32::consts::PI * rock_radius * rock_radius)
+    }
+    else {
+        let length = 8.0 + rng.next_f32() * 16.0;
+        let thickness = 1.0 + rng.next_f32();
+        let (width, height) = if rng.next_f32() < 0.5 { (length, thickness) } else { (thickness, length) };
+        let shape = Shape::Rectangle {
+            min: Coordinate { x: center.x - width / 2.0, y: center.y - height / 2.0 },
+            max: Coordinate { x: center.x + width / 2.0, y: center.y + height / 2.0 },
+        };
+        (TerrainFeature { kind: TerrainKind::Wall, shape, protection: None }, width * height)
+    }
+}
+
+/// `near` returns true if any part of `shape` lies within `BASE_CLEARANCE` of `point`.
+fn near(shape: &Shape, point: &Coordinate) -> bool {
+    let clearance = Shape::Circle { center: point.clone(), radius: BASE_CLEARANCE };
+    match shape {
+        Shape::Circle { center, radius } => center.distance(point) <= radius + BASE_CLEARANCE,
+        Shape::Rectangle { .. } | Shape::Polygon { .. } => {
+            let corners = shape.corners();
+            corners.iter().zip(corners.iter().cycle().skip(1)).any(|(start, end)| clearance.intersects_segment(start, end))
+        }
+    }
+}
+
+/// `Grid` is a square grid of points covering the map, and the straight moves a unit can make
+/// between neighbouring points.
+///
+/// `open` marks points inside the map and outside of every obstacle. `right` and `down` mark
+/// whether a unit can move from a point to its neighbour in that direction.
+#[derive(Clone)]
+struct Grid {
+    min: f32,
+    size: usize,
+    open: Vec<bool>,
+    right: Vec<bool>,
+    down: Vec<bool>,
+}
+
+impl Grid {
+    /// `new` accepts a `Map` without terrain, and returns a grid covering it.
+    fn new(map: &Map) -> Grid {
+        let radius = map.radius();
+        let size = (radius * 2.0 / GRID_SPACING).ceil() as usize + 1;
+        let mut grid = Grid { min: -radius, size, open: vec![false; size * size], right: vec![false; size * size], down: vec![false; size * size] };
+        for index in 0..size * size {
+            grid.open[index] = map.contains(&grid.point(index));
+        }
+        for index in 0..size * size {
+            let (column, row) = (index % size, index / size);
+            grid.right[index] = column + 1 < size && grid.open[index] && grid.open[index + 1];
+            grid.down[index] = row + 1 < size && grid.open[index] && grid.open[index + size];
+        }
+        grid
+    }
+
+    /// `point` accepts the index of a grid point, and returns its position on the map.
+    fn point(&self, index: usize) -> Coordinate {
+        Coordinate {
+            x: self.min + (index % self.size) as f32 * GRID_SPACING,
+            y: self.min + (index / self.size) as f32 * GRID_SPACING,
+        }
+    }
+
+    /// `nearest` accepts a `Coordinate`, and returns the index of the closest grid point.
+    fn nearest(&self, coord: &Coordinate) -> usize {
+        let column = (((coord.x - self.min) / GRID_SPACING).round() as usize).min(self.size - 1);
+        let row = (((coord.y - self.min) / GRID_SPACING).round() as usize).min(self.size - 1);
+        row * self.size + column
+    }
+
+    /// `block` accepts the `Shape` of an impassable obstacle, and closes every point inside it and
+    /// every move that passes through it.
+    fn block(&mut self, shape: &Shape) {
+        for index in 0..self.size * self.size {
+            let point = self.point(index);
+            if self.open[index] && shape.contains(&point) {
+                self.open[index] = false;
+            }
+            if self.right[index] && shape.intersects_segment(&point, &self.point(index + 1)) {
+                self.right[index] = false;
+            }
+            if self.down[index] && shape.intersects_segment(&point, &self.point(index + self.size)) {
+                self.down[index] = false;
+            }
+        }
+        for index in 0..self.size * self.size {
+            if !self.open[index] {
+                self.right[index] = false;
+                self.down[index] = false;
+                if index % self.size > 0 {
+                    self.right[index - 1] = false;
+                }
+                if index >= self.size {
+                    self.down[index - self.size] = false;
+                }
+            }
+        }
+    }
+
+    /// `is_connected` returns true if every open point in the map's spawn region, and every base,
+    /// can be reached from the first base.
+    fn is_connected(&self, map: &Map, bases: &[Coordinate]) -> bool {
+        let start = self.nearest(&bases[0]);
+        if !self.open[start] {
+            return false;
+        }
+        let mut reached = vec![false; self.size * self.size];
+        let mut queue = VecDeque::from([start]);
+        reached[start] = true;
+        while let Some(index) = queue.pop_front() {
+            let mut neighbours = Vec::with_capacity(4);
+            if self.right[index] {
+                neighbours.push(index + 1);
+            }
+            if self.down[index] {
+                neighbours.push(index + self.size);
+            }
+            if index % self.size > 0 && self.right[index - 1] {
+                neighbours.push(index - 1);
+            }
+            if index >= self.size && self.down[index - self.size] {
+                neighbours.push(index - self.size);
+            }
+            for neighbour in neighbours {
+                if !reached[neighbour] {
+                    reached[neighbour] = true;
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        let spawn_reached = match &map.spawn_region {
+            Some(spawn_region) => (0..self.size * self.size)
+                .filter(|index| self.open[*index] && spawn_region.contains(&self.point(*index)))
+                .all(|index| reached[index]),
+            None => true,
+        };
+        spawn_reached && bases.iter().all(|base| reached[self.nearest(base)])
+    }
+}
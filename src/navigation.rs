@@ -0,0 +1,168 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::game::Coordinate;
+use crate::map::{Map, Shape};
+
+/// How far outside an obstacle's corners the waypoints around it are placed, so that paths
+/// between them don't touch the obstacle.
+const CLEARANCE: f32 = 0.5;
+/// Number of waypoints placed around circular obstacles.
+const CIRCLE_WAYPOINTS: usize = 8;
+/// Number of halvings used to find how far along a line a unit can walk (see `clip_path`).
+const CLIP_STEPS: usize = 20;
+
+/// `find_path` accepts a `Map`, and the `start` and `goal` of a journey, and returns the cheapest
+/// route between them as a list of points, starting with `start` and ending with `goal`.
+///
+/// Routes are found with A* over a visibility graph: the nodes are `start`, `goal`, and waypoints
+/// just outside the corners of every obstacle, hole and map edge (see `waypoints`). Two nodes are
+/// joined if a unit can walk straight between them (see `Map::is_walkable`), and the cost of each
+/// step is `Map::path_cost`.
+///
+/// Returns `None` if `goal` can't be reached.
+pub fn find_path(map: &Map, start: &Coordinate, goal: &Coordinate) -> Option<Vec<Coordinate>> {
+    if map.is_walkable(start, goal) {
+        return Some(vec![start.clone(), goal.clone()]);
+    }
+    let mut nodes = vec![start.clone(), goal.clone()];
+    nodes.extend(waypoints(map).into_iter().filter(|point| map.contains(point) && map.is_passable(point)));
+
+    let mut costs = vec![f32::INFINITY; nodes.len()];
+    let mut previous: Vec<Option<usize>> = vec![None; nodes.len()];
+    let mut visited = vec![false; nodes.len()];
+    let mut queue = BinaryHeap::from([Step { node: 0, estimate: start.distance(goal) }]);
+    costs[0] = 0.0;
+
+    while let Some(Step { node, .. }) = queue.pop() {
+        if node == 1 {
+            let mut path = vec![goal.clone()];
+            let mut current = 1;
+            while let Some(before) = previous[current] {
+                path.push(nodes[before].clone());
+                current = before;
+            }
+            path.reverse();
+            return Some(path);
+        }
+        if visited[node] {
+            continue;
+        }
+        visited[node] = true;
+        for next in 0..nodes.len() {
+            if visited[next] || !map.is_walkable(&nodes[node], &nodes[next]) {
+                continue;
+            }
+            let cost = costs[node] + map.path_cost(&nodes[node], &nodes[next]);
+            if cost < costs[next] {
+                costs[next] = cost;
+                previous[next] = Some(node);
+                queue.push(Step { node: next, estimate: cost + nodes[next].distance(goal) });
+            }
+        }
+    }
+    None
+}
+
+/// `clip_path` accepts a `Map`, a `path` from `find_path`, and the `budget` of range a unit has
+/// this turn.
+///
+/// Units move in a straight line each turn, so they only follow the first leg of the path: as far
+/// along it as `budget` allows (see `Map::path_cost`).
+///
+/// Returns the point the unit can reach this turn, and the rest of the path after it.
+pub fn clip_path(map: &Map, path: &[Coordinate], budget: f32) -> (Coordinate, Vec<Coordinate>) {
+    let (start, end) = (&path[0], &path[1]);
+    if map.path_cost(start, end) <= budget {
+        return (end.clone(), path[2..].to_vec());
+    }
+    // Climbing makes the cost uneven along the line, so search for the farthest affordable point
+    let (mut reachable, mut unreachable) = (0.0, 1.0);
+    for _ in 0..CLIP_STEPS {
+        let fraction = (reachable + unreachable) / 2.0;
+        if map.path_cost(start, &start.along(end, fraction)) <= budget {
+            reachable = fraction;
+        }
+        else {
+            unreachable = fraction;
+        }
+    }
+    (start.along(end, reachable), path[1..].to_vec())
+}
+
+/// `waypoints` returns points just outside the corners of every impassable feature and hole on the
+/// map, and just inside the corners of its outer edge. Circles get `CIRCLE_WAYPOINTS` points
+/// around them.
+fn waypoints(map: &Map) -> Vec<Coordinate> {
+    let obstacles = map.terrain
+        .iter()
+        .filter(|feature| feature.kind.is_impassable())
+        .map(|feature| &feature.shape)
+        .chain(map.holes.iter());
+    let mut points: Vec<Coordinate> = obstacles.flat_map(|shape| offset_corners(shape, CLEARANCE)).collect();
+    if let Shape::Polygon { .. } = map.shape {
+        points.extend(offset_corners(&map.shape, -CLEARANCE));
+    }
+    points
+}
+
+/// `offset_corners` accepts a `Shape`, and returns its corners pushed `offset` away from its
+/// center along both axes. A negative `offset` pulls them in towards the center.
+///
+/// Circles are replaced by a regular polygon whose edges lie `offset` outside the circle.
+fn offset_corners(shape: &Shape, offset: f32) -> Vec<Coordinate> {
+    match shape {
+        Shape::Circle { center, radius } => {
+            let step = std::f32::consts::TAU / CIRCLE_WAYPOINTS as f32;
+            let distance = (radius + offset) / (step / 2.0).cos();
+            (0..CIRCLE_WAYPOINTS)
+                .map(|index| {
+                    let angle = index as f32 * step;
+                    Coordinate { x: center.x + distance * angle.cos(), y: center.y + distance * angle.sin() }
+                })
+                .collect()
+        }
+        Shape::Rectangle { .. } | Shape::Polygon { .. } => {
+            let corners = shape.corners();
+            let center = Coordinate {
+                x: corners.iter().map(|corner| corner.x).sum::<f32>() / corners.len() as f32,
+                y: corners.iter().map(|corner| corner.y).sum::<f32>() / corners.len() as f32,
+            };
+            // Corners move diagonally, so that they clear both of their edges
+            corners
+                .iter()
+                .map(|corner| Coordinate {
+                    x: corner.x + offset * (corner.x - center.x).signum(),
+                    y: corner.y + offset * (corner.y - center.y).signum(),
+                })
+                .collect()
+        }
+    }
+}
+
+/// `Step` is an entry in the A* queue: a node, and the estimated cost of the cheapest route to the
+/// goal through it. Steps are ordered so that `BinaryHeap` pops the lowest estimate first.
+struct Step {
+    node: usize,
+    estimate: f32,
+}
+
+impl PartialEq for Step {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl Eq for Step {}
+
+impl PartialOrd for Step {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Step {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.total_cmp(&self.estimate)
+    }
+}
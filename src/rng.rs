@@ -0,0 +1,39 @@
+use crate::game::Coordinate;
+
+/// `Rng` is a small, seeded pseudo-random number generator (SplitMix64).
+///
+/// The game only needs a reproducible stream of numbers: the same seed always produces the same
+/// sequence, so a game can be replayed from its seed and the orders given by both players.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// `new` accepts a `seed`, and creates a generator starting from that seed.
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    /// `next_u64` advances the generator and returns the next 64 random bits.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// `next_f32` returns a float in the range `0.0..1.0`.
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// `point_in_circle` accepts a `radius`, and returns an offset that is uniformly distributed
+    /// within a circle of that radius centered on 0,0.
+    pub fn point_in_circle(&mut self, radius: f32) -> Coordinate {
+        let angle = self.next_f32() * std::f32::consts::TAU;
+        let distance = radius * self.next_f32().sqrt();
+        Coordinate { x: distance * angle.cos(), y: distance * angle.sin() }
+    }
+}